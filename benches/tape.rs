@@ -0,0 +1,243 @@
+//! Benchmarks comparing the buffer-backed `TuringTape` to the linked list of `Node`s it
+//! replaced.
+//!
+//! Run with `cargo bench --bench tape`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use turingmachine_rs::TuringTape;
+
+/// The previous `Node` based implementation of the TuringTape, kept as a baseline
+mod linked {
+    use std::cell::RefCell;
+    use std::rc::{Rc, Weak};
+
+    /// A struct representing a node in a linked list
+    struct Node<Alphabet> {
+        /// The previous node
+        prev: RefCell<Option<Rc<Node<Alphabet>>>>,
+        /// The next node
+        next: RefCell<Option<Weak<Node<Alphabet>>>>,
+        /// The data contained in the node
+        data: RefCell<Alphabet>,
+    }
+
+    impl<Alphabet: Clone> Node<Alphabet> {
+        fn new(data: Alphabet, prev: Option<Rc<Node<Alphabet>>>) -> Node<Alphabet> {
+            Node {
+                prev: RefCell::new(prev),
+                next: RefCell::new(None),
+                data: RefCell::new(data),
+            }
+        }
+    }
+
+    /// A two-way infinite TuringTape built from linked nodes
+    pub struct LinkedTape<Alphabet> {
+        empty: Alphabet,
+        last: RefCell<Rc<Node<Alphabet>>>,
+        cursor: RefCell<Rc<Node<Alphabet>>>,
+    }
+
+    impl<Alphabet: Clone> LinkedTape<Alphabet> {
+        pub fn new(empty: Alphabet, start: Alphabet, initial: Vec<Alphabet>) -> Self {
+            let fst_node = Rc::new(Node::new(start, None));
+            let tape = LinkedTape {
+                empty,
+                last: RefCell::new(fst_node.clone()),
+                cursor: RefCell::new(fst_node),
+            };
+
+            initial.into_iter().for_each(|token| {
+                tape.append(token);
+            });
+
+            tape
+        }
+
+        fn prepend(&self, first: &Rc<Node<Alphabet>>, token: Alphabet) -> Rc<Node<Alphabet>> {
+            let new_node = Rc::new(Node::new(token, None));
+            new_node.next.replace(Some(Rc::downgrade(first)));
+            first.prev.replace(Some(new_node.clone()));
+            new_node
+        }
+
+        fn append(&self, token: Alphabet) -> Rc<Node<Alphabet>> {
+            let new_node = Rc::new(Node::new(token, Some(self.last.borrow().clone())));
+            self.last
+                .borrow()
+                .next
+                .replace(Some(Rc::downgrade(&new_node)));
+            self.last.replace(new_node.clone());
+            new_node
+        }
+
+        pub fn get_cursor(&self) -> Alphabet {
+            self.cursor.borrow().data.borrow().clone()
+        }
+
+        pub fn set_cursor(&self, value: Alphabet) -> Alphabet {
+            self.cursor.borrow().data.replace(value)
+        }
+
+        pub fn step_right(&self) -> Alphabet {
+            let next = self.cursor.borrow().next.borrow().clone();
+            let new_cursor = match next {
+                Some(next) => next.upgrade().expect("Unable to upgrade"),
+                None => self.append(self.empty.clone()),
+            };
+
+            self.cursor.replace(new_cursor);
+            self.get_cursor()
+        }
+
+        pub fn step_left(&self) -> Alphabet {
+            let cursor = self.cursor.borrow().clone();
+            let prev = cursor.prev.borrow().clone();
+            let new_cursor = match prev {
+                Some(prev) => prev,
+                None => self.prepend(&cursor, self.empty.clone()),
+            };
+
+            self.cursor.replace(new_cursor);
+            self.get_cursor()
+        }
+    }
+
+    /// Dropping a long chain of `Rc`s recursively overflows the stack, so unlink the nodes one
+    /// by one instead.
+    impl<Alphabet> Drop for LinkedTape<Alphabet> {
+        fn drop(&mut self) {
+            let mut head = self.last.borrow_mut().prev.take();
+            while let Some(node) = head {
+                head = node.prev.take();
+            }
+        }
+    }
+}
+
+/// The operations used by the benchmark workloads
+trait Tape {
+    fn get_cursor(&self) -> bool;
+    fn set_cursor(&self, value: bool) -> bool;
+    fn step_left(&self) -> bool;
+    fn step_right(&self) -> bool;
+}
+
+impl Tape for TuringTape<bool> {
+    fn get_cursor(&self) -> bool {
+        TuringTape::get_cursor(self)
+    }
+    fn set_cursor(&self, value: bool) -> bool {
+        TuringTape::set_cursor(self, value)
+    }
+    fn step_left(&self) -> bool {
+        TuringTape::step_left(self)
+    }
+    fn step_right(&self) -> bool {
+        TuringTape::step_right(self)
+    }
+}
+
+impl Tape for linked::LinkedTape<bool> {
+    fn get_cursor(&self) -> bool {
+        linked::LinkedTape::get_cursor(self)
+    }
+    fn set_cursor(&self, value: bool) -> bool {
+        linked::LinkedTape::set_cursor(self, value)
+    }
+    fn step_left(&self) -> bool {
+        linked::LinkedTape::step_left(self)
+    }
+    fn step_right(&self) -> bool {
+        linked::LinkedTape::step_right(self)
+    }
+}
+
+const STEPS: usize = 1_000_000;
+
+/// Keep walking into fresh cells at the right
+fn grow_right(tape: &impl Tape) {
+    for _ in 0..STEPS {
+        black_box(tape.step_right());
+    }
+}
+
+/// Sweep back and forth over an ever wider region, flipping every visited cell, like a
+/// counter or a busy beaver does
+fn zigzag(tape: &impl Tape) {
+    let mut steps = 0;
+    let mut width = 1;
+    while steps < STEPS {
+        for _ in 0..width {
+            let token = tape.get_cursor();
+            tape.set_cursor(!token);
+            tape.step_right();
+        }
+        for _ in 0..width + 1 {
+            let token = tape.get_cursor();
+            tape.set_cursor(!token);
+            tape.step_left();
+        }
+        steps += 2 * width + 1;
+        width += 2;
+    }
+}
+
+/// Read and write the cell at the cursor without moving
+fn read_write(tape: &impl Tape) {
+    for _ in 0..STEPS {
+        let token = black_box(tape.get_cursor());
+        tape.set_cursor(!token);
+    }
+}
+
+/// Run a workload a few times on a fresh tape and return the fastest run
+fn measure<T: Tape>(new_tape: impl Fn() -> T, workload: fn(&T)) -> Duration {
+    (0..5)
+        .map(|_| {
+            let tape = new_tape();
+            let start = Instant::now();
+            workload(&tape);
+            let elapsed = start.elapsed();
+            drop(black_box(tape));
+            elapsed
+        })
+        .min()
+        .unwrap()
+}
+
+/// Measure a workload on both tape implementations and print the comparison
+fn compare(
+    name: &str,
+    buffer_workload: fn(&TuringTape<bool>),
+    linked_workload: fn(&linked::LinkedTape<bool>),
+) {
+    let buffer = measure(
+        || TuringTape::new_two_way(false, false, vec![]),
+        buffer_workload,
+    );
+    let linked = measure(
+        || linked::LinkedTape::new(false, false, vec![]),
+        linked_workload,
+    );
+
+    println!(
+        "{:<12} {:>14?} {:>14?} {:>7.1}x",
+        name,
+        buffer,
+        linked,
+        linked.as_secs_f64() / buffer.as_secs_f64()
+    );
+}
+
+fn main() {
+    println!(
+        "{:<12} {:>14} {:>14} {:>8}",
+        "workload", "buffer", "linked", "speedup"
+    );
+    compare("grow_right", grow_right, grow_right);
+    compare("zigzag", zigzag, zigzag);
+    compare("read_write", read_write, read_write);
+}
@@ -2,7 +2,7 @@
 //! supplied with two numbers `n` and `k` will determine whether `n` is divisable by `k`.
 //!
 //! Its usage is in the CLI by adding 2 args with numbers:
-//! `cargo run --example divisibility -- 9 6`
+//! `cargo run --features derive --example divisibility -- 9 6`
 //!
 //! The turing machine starts with a tape in the shape:
 //! ` _ 1^n _ 1^k _ ` and will output:
@@ -11,183 +11,136 @@
 //!
 //! here ` _ ` is a empty cell and ` 1^n ` is ` 1 ` repeated n times, and ` h ` is the halt symbol.
 
-use std::fmt;
 use turingmachine_rs::*;
 
-#[derive(Clone, PartialEq)]
+#[derive(TapeSymbol, Clone, PartialEq)]
 enum Alphabet {
+    #[symbol('S')]
     StartToken,
+    #[symbol('_')]
     Delta,
+    #[symbol('0')]
     Zero,
+    #[symbol('1')]
     One,
+    #[symbol('!')]
     MarkedOne,
+    #[symbol('h')]
     Halt,
 }
 
-impl fmt::Display for Alphabet {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        use Alphabet::*;
-
-        match self {
-            StartToken => write!(f, "S"),
-            Delta => write!(f, "_"),
-            Zero => write!(f, "0"),
-            One => write!(f, "1"),
-            MarkedOne => write!(f, "!"),
-            Halt => write!(f, "h"),
-        }
+turing_machine! {
+    enum States: Alphabet {
+        Start,
+        MovingToCenter,
+        CycleStart,
+        CycleEnd,
+
+        DivisibleReturn,
+        NonDivisibleReturn,
+
+        DivHalt,
+        DivDelta,
+        DivOutput,
+
+        NonDivHalt,
+        NonDivDelta,
+        NonDivOutput,
+
+        SearchingDen,
+        SearchingNum,
+        Found1InDenominator,
+        Found1InNuminator,
+
+        CheckingDivByNull,
+
+        CheckingLeftovers,
+        FoundLeftovers,
+
+        NoLeftoversP1,
+        NoLeftoversP2,
+
+        LeftOverP1,
+        LeftOverP2,
+
+        DivByNull,
+        InvalidSyntax,
+
+        Done
     }
-}
 
-#[derive(Debug, Clone, PartialEq, Copy)]
-enum States {
-    Start,
-    MovingToCenter,
-    CycleStart,
-    CycleEnd,
+    end_states = [InvalidSyntax, DivByNull, Done];
 
-    DivisibleReturn,
-    NonDivisibleReturn,
+    Start, Delta => MovingToCenter, _, R;
+    Start, _ => Start, _, R;
 
-    DivHalt,
-    DivDelta,
-    DivOutput,
+    MovingToCenter, One => MovingToCenter, _, R;
+    MovingToCenter, Delta => CheckingDivByNull, _, R;
+    MovingToCenter, _ => InvalidSyntax, _, S;
 
-    NonDivHalt,
-    NonDivDelta,
-    NonDivOutput,
+    CheckingDivByNull, Delta => DivByNull, _, S;
+    CheckingDivByNull, _ => CycleStart, _, L;
 
-    SearchingDen,
-    SearchingNum,
-    Found1InDenominator,
-    Found1InNuminator,
+    CycleStart, _ => CheckingLeftovers, _, L;
 
-    CheckingDivByNull,
+    CheckingLeftovers, Delta => NoLeftoversP1, _, R;
+    CheckingLeftovers, One => FoundLeftovers, _, R;
+    CheckingLeftovers, _ => CheckingLeftovers, _, L;
 
-    CheckingLeftovers,
-    FoundLeftovers,
+    FoundLeftovers, Delta => SearchingDen, _, R;
+    FoundLeftovers, _ => FoundLeftovers, _, R;
 
-    NoLeftoversP1,
-    NoLeftoversP2,
+    SearchingDen, Delta => CycleEnd, _, L;
+    SearchingDen, One => Found1InDenominator, MarkedOne, L;
+    SearchingDen, _ => SearchingDen, _, R;
 
-    LeftOverP1,
-    LeftOverP2,
-    
-    DivByNull,
-    InvalidSyntax,
+    Found1InDenominator, Delta => SearchingNum, _, L;
+    Found1InDenominator, _ => Found1InDenominator, _, L;
 
-    Done
-}
+    SearchingNum, Delta => LeftOverP1, _, R;
+    SearchingNum, One => Found1InNuminator, MarkedOne, R;
+    SearchingNum, _ => SearchingNum, _, L;
 
-impl TuringStates<Alphabet> for States {
-    fn step(&self, t: Alphabet) -> (Self, Alphabet, Move) {
-        use Alphabet::*;
-        use States::*;
-
-        match self {
-            Start => match t {
-                Delta => (MovingToCenter, t, Move::Right),
-                _ => (Start, t, Move::Right),
-            },
-
-            MovingToCenter => match t {
-                One => (MovingToCenter, t, Move::Right),
-                Delta => (CheckingDivByNull, t, Move::Right),
-                _ => (InvalidSyntax, t, Move::Stay),
-            },
-
-            CheckingDivByNull => match t {
-                Delta => (DivByNull, t, Move::Stay),
-                _ => (CycleStart, t, Move::Left),
-            },
-
-            CycleStart => (CheckingLeftovers, t, Move::Left),
-
-            CheckingLeftovers => match t {
-                Delta => (NoLeftoversP1, t, Move::Right),
-                One => (FoundLeftovers, t, Move::Right),
-                _ => (CheckingLeftovers, t, Move::Left),
-            },
-
-            FoundLeftovers => match t {
-                Delta => (SearchingDen, t, Move::Right),
-                _ => (FoundLeftovers, t, Move::Right),
-            },
-
-            SearchingDen => match t {
-                Delta => (CycleEnd, t, Move::Left),
-                One => (Found1InDenominator, MarkedOne, Move::Left),
-                _ => (SearchingDen, t, Move::Right),
-            },
-
-            Found1InDenominator => match t {
-                Delta => (SearchingNum, t, Move::Left),
-                _ => (Found1InDenominator, t, Move::Left),
-            },
-
-            SearchingNum => match t {
-                Delta => (LeftOverP1, t, Move::Right),
-                One => (Found1InNuminator, MarkedOne, Move::Right),
-                _ => (SearchingNum, t, Move::Left),
-            },
-
-            Found1InNuminator => match t {
-                Delta => (SearchingDen, t, Move::Right),
-                _ => (Found1InNuminator, t, Move::Right),
-            },
-
-            CycleEnd => match t {
-                Delta => (CycleStart, t, Move::Stay),
-                MarkedOne => (CycleEnd, One, Move::Left),
-                _ => panic!("Something went wrong"),
-            },
-
-            LeftOverP1 => match t {
-                Delta => (LeftOverP2, t, Move::Right),
-                MarkedOne => (LeftOverP1, One, Move::Right),
-                _ => (LeftOverP1, t, Move::Right),
-            }
+    Found1InNuminator, Delta => SearchingDen, _, R;
+    Found1InNuminator, _ => Found1InNuminator, _, R;
 
-            LeftOverP2 => match t {
-                Delta => (NonDivisibleReturn, t, Move::Right),
-                MarkedOne => (LeftOverP2, One, Move::Right),
-                _ => (LeftOverP2, t, Move::Right),
-            }
+    CycleEnd, Delta => CycleStart, _, S;
+    CycleEnd, MarkedOne => CycleEnd, One, L;
 
-            NoLeftoversP1 => match t {
-                Delta => (NoLeftoversP2, t, Move::Right),
-                MarkedOne => (NoLeftoversP1, One, Move::Right),
-                _ => (NoLeftoversP1, t, Move::Right),
-            }
+    LeftOverP1, Delta => LeftOverP2, _, R;
+    LeftOverP1, MarkedOne => LeftOverP1, One, R;
+    LeftOverP1, _ => LeftOverP1, _, R;
 
-            NoLeftoversP2 => match t {
-                Delta => (DivisibleReturn, t, Move::Right),
-                MarkedOne => (NoLeftoversP2, One, Move::Right),
-                _ => (NoLeftoversP2, t, Move::Right),
-            }
-            
-            DivisibleReturn => (DivHalt, t, Move::Stay),            
-            DivHalt => (DivDelta, Halt, Move::Right),            
-            DivDelta => (DivOutput, Delta, Move::Right),            
-            DivOutput => (Done, One, Move::Right),            
-
-            NonDivisibleReturn => (NonDivHalt, t, Move::Stay),            
-            NonDivHalt => (NonDivDelta, Halt, Move::Right),            
-            NonDivDelta => (NonDivOutput, Delta, Move::Right),            
-            NonDivOutput => (Done, Zero, Move::Right),            
-
-            _ => (InvalidSyntax, t, Move::Stay),
-        }
-    }
+    LeftOverP2, Delta => NonDivisibleReturn, _, R;
+    LeftOverP2, MarkedOne => LeftOverP2, One, R;
+    LeftOverP2, _ => LeftOverP2, _, R;
+
+    NoLeftoversP1, Delta => NoLeftoversP2, _, R;
+    NoLeftoversP1, MarkedOne => NoLeftoversP1, One, R;
+    NoLeftoversP1, _ => NoLeftoversP1, _, R;
+
+    NoLeftoversP2, Delta => DivisibleReturn, _, R;
+    NoLeftoversP2, MarkedOne => NoLeftoversP2, One, R;
+    NoLeftoversP2, _ => NoLeftoversP2, _, R;
+
+    DivisibleReturn, _ => DivHalt, _, S;
+    DivHalt, _ => DivDelta, Halt, R;
+    DivDelta, _ => DivOutput, Delta, R;
+    DivOutput, _ => Done, One, R;
+
+    NonDivisibleReturn, _ => NonDivHalt, _, S;
+    NonDivHalt, _ => NonDivDelta, Halt, R;
+    NonDivDelta, _ => NonDivOutput, Delta, R;
+    NonDivOutput, _ => Done, Zero, R;
 }
 
 fn main() {
     let n = std::env::args()
         .nth(1)
-        .expect("No first argument given. Usage: cargo run --example divisibility -- <int> <int>");
+        .expect("No first argument given. Usage: cargo run --features derive --example divisibility -- <int> <int>");
     let k = std::env::args()
         .nth(2)
-        .expect("No second argument given. Usage: cargo run --example divisibility -- <int> <int>");
+        .expect("No second argument given. Usage: cargo run --features derive --example divisibility -- <int> <int>");
 
     let n = n
         .parse::<usize>()
@@ -198,32 +151,47 @@ fn main() {
 
     println!("n: {}, k: {}", n, k);
 
-    let mut ns = vec![Alphabet::One; n];
-    let mut ks = vec![Alphabet::One; k];
-
-    let mut delta = vec![Alphabet::Delta];
-
-    let mut initial = Vec::new();
-
-    initial.append(&mut delta.clone());
-    initial.append(&mut ns);
-    initial.append(&mut delta.clone());
-    initial.append(&mut ks);
-    initial.append(&mut delta);
+    let divides = ComputableFunction::new(
+        States::Start,
+        States::end_states(),
+        Alphabet::Delta,
+        Alphabet::StartToken,
+        |(n, k): (usize, usize)| {
+            let mut initial = vec![Alphabet::Delta];
+            initial.append(&mut Unary::new(Alphabet::One, Alphabet::Delta).encode((n, k)));
+            initial.push(Alphabet::Delta);
+            initial
+        },
+        |tape: &TuringTape<Alphabet>| {
+            let snapshot = tape.snapshot();
+            let mut output = snapshot
+                .cells()
+                .iter()
+                .skip_while(|t| **t != Alphabet::Halt)
+                .filter(|t| **t != Alphabet::Delta);
+            match output.nth(1) {
+                Some(Alphabet::One) => Ok(true),
+                Some(Alphabet::Zero) => Ok(false),
+                _ => Err(Error::Format(
+                    "no halt symbol followed by a result".to_string(),
+                )),
+            }
+        },
+    );
 
-    let tape = TuringTape::new(Alphabet::Delta, Alphabet::StartToken, initial);
+    let tape = divides.tape((n, k));
     println!("Tape: {}", tape);
 
     println!("\n\nRunning...\n\n");
 
-    use States::*;
-    let end_state = tape.debug_run_states(
-        Start,
-        vec![InvalidSyntax, DivByNull, Done],
-    );
+    let end_state = tape.debug_run_states(States::Start, States::end_states());
 
     println!("\n\nFinished Running...\n\n");
 
     println!("Tape: {}", tape);
     println!("Endstate: {:?}", end_state);
-}
\ No newline at end of file
+    match divides.decode(&tape) {
+        Ok(divisible) => println!("Divisible: {}", divisible),
+        Err(error) => println!("No output: {}", error),
+    }
+}
@@ -0,0 +1,206 @@
+//! The standard text format of the busy beaver challenge, e.g. `1RB1LB_1LA1RZ`
+//!
+//! Every state, named `A`, `B`, `C`, etc. in order, is a group separated by `_`. A group lists
+//! the transition for every symbol `0`, `1`, etc. in order, as the symbol to write, the move `L`
+//! or `R`, and the next state. Undefined transitions are written as `---`. Next states without a
+//! group of their own, such as the common `Z`, are halting states.
+
+use crate::{Error, Move, TransitionTable};
+
+impl TransitionTable<char, u8> {
+    /// Parse a machine from the busy beaver challenge standard text format
+    ///
+    /// The machine starts in state `A` and all states without transitions are accepting end
+    /// states, so it can be run with
+    /// [`TuringTape::run_states`](crate::TuringTape::run_states) on a two-way infinite tape of
+    /// `0`s. Undefined `---` transitions halt the machine as well: `run_states` returns the
+    /// state it halted in and
+    /// [`TuringTape::run_states_limited`](crate::TuringTape::run_states_limited) reports it as
+    /// [`RunOutcome::UndefinedTransition`](crate::RunOutcome::UndefinedTransition).
+    ///
+    /// ```
+    /// use turingmachine_rs::{TransitionTable, TuringTape};
+    ///
+    /// let table = TransitionTable::from_bbchallenge("1RB1LB_1LA1RZ").unwrap();
+    /// let tape = TuringTape::new_two_way(0, 0, vec![]);
+    /// let end_state = tape.run_states(table.start(), table.end_states());
+    ///
+    /// assert_eq!(end_state.state(), &'Z');
+    /// assert_eq!(<Vec<u8>>::from(tape), vec![1, 1, 1, 1]);
+    /// ```
+    pub fn from_bbchallenge(text: &str) -> Result<TransitionTable<char, u8>, Error> {
+        let groups: Vec<&str> = text.trim().split('_').collect();
+        if groups.len() > 26 {
+            return Err(Error::Format(format!(
+                "{} states do not fit in the letters A to Z",
+                groups.len()
+            )));
+        }
+
+        let group_len = groups[0].len();
+        if group_len == 0 || !group_len.is_multiple_of(3) || group_len / 3 > 10 {
+            return Err(Error::Format(format!(
+                "state group '{}' does not consist of one to ten transitions",
+                groups[0]
+            )));
+        }
+
+        let mut table = TransitionTable::new('A');
+        let mut next_states = Vec::new();
+
+        for (state, group) in state_names().zip(groups.iter()) {
+            if group.len() != group_len || !group.is_ascii() {
+                return Err(Error::Format(format!(
+                    "state group '{}' differs in length from '{}'",
+                    group, groups[0]
+                )));
+            }
+
+            for (symbol, transition) in (0..).zip(group.as_bytes().chunks(3)) {
+                if transition == b"---" {
+                    continue;
+                }
+
+                let (write, mv, next) = parse_transition(transition)?;
+                next_states.push(next);
+                table = table.transition(state, symbol, next, write, mv);
+            }
+        }
+
+        let mut halting: Vec<char> = next_states
+            .into_iter()
+            .filter(|next| (*next as usize - 'A' as usize) >= groups.len())
+            .collect();
+        halting.sort_unstable();
+        halting.dedup();
+
+        Ok(halting
+            .into_iter()
+            .fold(table, |table, state| table.accept(state)))
+    }
+
+    /// Serialize the machine into the busy beaver challenge standard text format
+    ///
+    /// The states have to be the letters `A` to `Z` and the symbols `0` to `9`. The machine has
+    /// to start in `A` and every state up to the last state with a transition gets a group, so
+    /// exactly the states after it have to be accepting. Will return an error if the machine
+    /// cannot be represented, e.g. when it contains [`Move::Stay`] or rejecting states.
+    pub fn to_bbchallenge(&self) -> Result<String, Error> {
+        if *self.start_state() != 'A' {
+            return Err(Error::Format(format!(
+                "start state '{}' is not A",
+                self.start_state()
+            )));
+        }
+
+        let mut state_count = 0;
+        let mut symbol_count = 2;
+
+        for ((state, read), (next, write, _)) in self.transitions() {
+            for state in [state, next].iter() {
+                if !state.is_ascii_uppercase() {
+                    return Err(Error::Format(format!("state '{}' is not a letter", state)));
+                }
+            }
+            for symbol in [read, write].iter() {
+                if **symbol > 9 {
+                    return Err(Error::Format(format!("symbol {} is not a digit", symbol)));
+                }
+            }
+
+            state_count = state_count.max(*state as usize - 'A' as usize + 1);
+            symbol_count = symbol_count
+                .max(*read as usize + 1)
+                .max(*write as usize + 1);
+        }
+
+        // Only states past the last group are read back as accepting
+        for state in self.accept_states() {
+            if !state.is_ascii_uppercase() || (*state as usize - 'A' as usize) < state_count {
+                return Err(Error::Format(format!(
+                    "accepting state '{}' would have a group",
+                    state
+                )));
+            }
+        }
+        for (_, (next, _, _)) in self.transitions() {
+            if (*next as usize - 'A' as usize) >= state_count
+                && !self.accept_states().contains(next)
+            {
+                return Err(Error::Format(format!(
+                    "state '{}' would be accepting without a group",
+                    next
+                )));
+            }
+        }
+        if state_count == 0 {
+            return Err(Error::Format("the machine has no transitions".to_string()));
+        }
+        if let Some(state) = self.reject_states().first() {
+            return Err(Error::Format(format!(
+                "rejecting state '{}' cannot be represented",
+                state
+            )));
+        }
+
+        let mut groups = Vec::with_capacity(state_count);
+        for state in state_names().take(state_count) {
+            let mut group = String::with_capacity(3 * symbol_count);
+
+            for symbol in 0..symbol_count as u8 {
+                match self.get(&state, &symbol) {
+                    None => group.push_str("---"),
+                    Some((next, write, mv)) => {
+                        group.push((b'0' + write) as char);
+                        group.push(match mv {
+                            Move::Left => 'L',
+                            Move::Right => 'R',
+                            Move::Stay => {
+                                return Err(Error::Format(format!(
+                                    "transition of state {} on {} does not move",
+                                    state, symbol
+                                )))
+                            }
+                        });
+                        group.push(*next);
+                    }
+                }
+            }
+
+            groups.push(group);
+        }
+
+        Ok(groups.join("_"))
+    }
+}
+
+/// The names of the states in order
+fn state_names() -> impl Iterator<Item = char> {
+    'A'..='Z'
+}
+
+/// Parse a single transition such as `1RB`
+fn parse_transition(transition: &[u8]) -> Result<(u8, Move, char), Error> {
+    let invalid = || {
+        Error::Format(format!(
+            "invalid transition '{}'",
+            String::from_utf8_lossy(transition)
+        ))
+    };
+
+    let write = match transition[0] {
+        symbol @ b'0'..=b'9' => symbol - b'0',
+        _ => return Err(invalid()),
+    };
+    let mv = match transition[1] {
+        b'L' => Move::Left,
+        b'R' => Move::Right,
+        _ => return Err(invalid()),
+    };
+    let next = match transition[2] {
+        state @ b'A'..=b'Z' => state as char,
+        _ => return Err(invalid()),
+    };
+
+    Ok((write, mv, next))
+}
@@ -0,0 +1,128 @@
+use std::hash::Hash;
+
+use crate::{TableState, TuringStates, TuringTape};
+
+/// A turing machine recognizing a language, which declares its accepting and rejecting halt
+/// states
+///
+/// A state which is neither accepting nor rejecting is not a halt state. Halting because no
+/// transition is defined or because the cursor went off the left side of the tape counts as
+/// rejecting the input.
+pub trait Decider<Alphabet: Clone>: TuringStates<Alphabet> {
+    /// Returns whether the machine halts and accepts the input in this state
+    fn is_accepting(&self) -> bool;
+
+    /// Returns whether the machine halts and rejects the input in this state
+    fn is_rejecting(&self) -> bool;
+}
+
+/// The decision of a [`Decider`] about an input
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Verdict {
+    /// The machine halted in an accepting state
+    Accept,
+    /// The machine halted in a rejecting state or could not continue
+    Reject,
+    /// The machine did not halt within the given amount of steps
+    Undecided(usize),
+}
+
+impl<Alphabet: Clone> TuringTape<Alphabet> {
+    /// Runs from start state until an accepting or rejecting state has been reached or
+    /// `max_steps` steps have been taken. Will return whether the tape was accepted.
+    pub fn decide<S: Decider<Alphabet>>(&self, mut state: S, max_steps: usize) -> Verdict {
+        let mut steps = 0;
+
+        loop {
+            if state.is_accepting() {
+                return Verdict::Accept;
+            }
+            if state.is_rejecting() {
+                return Verdict::Reject;
+            }
+            if steps == max_steps {
+                return Verdict::Undecided(max_steps);
+            }
+            if state.try_internal_step(self).is_err() {
+                return Verdict::Reject;
+            }
+
+            steps += 1;
+        }
+    }
+}
+
+/// A [`Decider`] bundled with how inputs are put on a tape, to test it against words
+///
+/// ```
+/// use turingmachine_rs::{Move, Recognizer, TransitionTable, Verdict};
+///
+/// // Accepts the words starting with a 1
+/// let table = TransitionTable::new("start")
+///     .transition("start", '_', "first", '_', Move::Right)
+///     .transition("first", '1', "yes", '1', Move::Stay)
+///     .transition("first", '0', "no", '0', Move::Stay)
+///     .accept("yes")
+///     .reject("no");
+/// let recognizer = Recognizer::new(table.start(), '_', '_', 100);
+///
+/// assert!(recognizer.accepts("10".chars().collect()));
+/// assert_eq!(recognizer.decide("01".chars().collect()), Verdict::Reject);
+/// assert_eq!(recognizer.decide(vec![]), Verdict::Reject);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Recognizer<Alphabet, S> {
+    /// The state to start in
+    start: S,
+    /// The token put at empty tape cells
+    empty: Alphabet,
+    /// The token put in the first cell, before the input
+    start_token: Alphabet,
+    /// The maximum amount of steps taken before giving up
+    max_steps: usize,
+}
+
+impl<Alphabet: Clone, S: Decider<Alphabet> + Clone> Recognizer<Alphabet, S> {
+    /// Initialize a new Recognizer with:
+    ///
+    /// - __start:__ The state to start in
+    /// - __empty:__ The token put at empty tape cells
+    /// - __start_token:__ The token put in the first cell, before the input
+    /// - __max_steps:__ The maximum amount of steps taken before the input is undecided
+    pub fn new(
+        start: S,
+        empty: Alphabet,
+        start_token: Alphabet,
+        max_steps: usize,
+    ) -> Recognizer<Alphabet, S> {
+        Recognizer {
+            start,
+            empty,
+            start_token,
+            max_steps,
+        }
+    }
+
+    /// Run the machine on an input and return its verdict
+    pub fn decide(&self, input: Vec<Alphabet>) -> Verdict {
+        let tape = TuringTape::new(self.empty.clone(), self.start_token.clone(), input);
+        tape.decide(self.start.clone(), self.max_steps)
+    }
+
+    /// Returns whether the machine accepts an input within the maximum amount of steps
+    pub fn accepts(&self, input: Vec<Alphabet>) -> bool {
+        self.decide(input) == Verdict::Accept
+    }
+}
+
+impl<'a, State: Clone + Eq + Hash, Alphabet: Clone + Eq + Hash> Decider<Alphabet>
+    for TableState<'a, State, Alphabet>
+{
+    fn is_accepting(&self) -> bool {
+        TableState::is_accepting(self)
+    }
+
+    fn is_rejecting(&self) -> bool {
+        TableState::is_rejecting(self)
+    }
+}
@@ -0,0 +1,175 @@
+//! Graphviz DOT export of state transition diagrams
+
+use std::fmt;
+use std::hash::Hash;
+
+use crate::{Move, TransitionTable, TuringStates};
+
+impl<State: Clone + Eq + Hash + fmt::Display, Alphabet: Clone + Eq + Hash + fmt::Display>
+    TransitionTable<State, Alphabet>
+{
+    /// Draw the state transition diagram of the machine as a Graphviz DOT graph
+    ///
+    /// Edges are labelled `read → write, L/S/R`, the start state has an incoming arrow, the
+    /// accepting end states are drawn with a double circle and the rejecting end states with an
+    /// octagon.
+    pub fn to_dot(&self) -> String {
+        let mut states: Vec<&State> = self
+            .transitions()
+            .flat_map(|((from, _), (to, _, _))| vec![from, to])
+            .chain(std::iter::once(self.start_state()))
+            .chain(self.accept_states())
+            .chain(self.reject_states())
+            .collect();
+        states.sort_by_key(|state| state.to_string());
+        states.dedup();
+
+        let nodes = states
+            .into_iter()
+            .map(|state| Node {
+                name: state.to_string(),
+                start: state == self.start_state(),
+                shape: if self.accept_states().contains(state) {
+                    Some("doublecircle")
+                } else if self.reject_states().contains(state) {
+                    Some("octagon")
+                } else {
+                    None
+                },
+            })
+            .collect();
+
+        let mut edges: Vec<Edge> = self
+            .transitions()
+            .map(|((from, read), (to, write, mv))| Edge {
+                from: from.to_string(),
+                to: to.to_string(),
+                label: label(read, write, *mv),
+            })
+            .collect();
+        edges.sort_by(|a, b| (&a.from, &a.to, &a.label).cmp(&(&b.from, &b.to, &b.label)));
+
+        write_dot(nodes, edges)
+    }
+}
+
+/// Draw the state transition diagram of a [`TuringStates`] implementation as a Graphviz DOT
+/// graph, by calling [`TuringStates::try_step`] for every state and token which are given
+///
+/// The end states are not stepped from, transitions which are not defined are left out and
+/// states are named by their [`fmt::Debug`] output. Edges are labelled `read → write, L/S/R`,
+/// the start state has an incoming arrow and the end states are drawn with a double circle.
+pub fn states_to_dot<Alphabet, S>(
+    states: &[S],
+    alphabet: &[Alphabet],
+    start_state: &S,
+    end_states: &[S],
+) -> String
+where
+    Alphabet: Clone + fmt::Display,
+    S: TuringStates<Alphabet> + fmt::Debug,
+{
+    let nodes = states
+        .iter()
+        .map(|state| Node {
+            name: format!("{:?}", state),
+            start: state == start_state,
+            shape: Some("doublecircle").filter(|_| end_states.contains(state)),
+        })
+        .collect();
+
+    let mut edges = Vec::new();
+    for state in states.iter().filter(|state| !end_states.contains(state)) {
+        for read in alphabet {
+            if let Ok((to, write, mv)) = state.try_step(read.clone()) {
+                edges.push(Edge {
+                    from: format!("{:?}", state),
+                    to: format!("{:?}", to),
+                    label: label(read, &write, mv),
+                });
+            }
+        }
+    }
+
+    write_dot(nodes, edges)
+}
+
+/// A state in the diagram
+struct Node {
+    /// The name of the state
+    name: String,
+    /// Whether this is the start state
+    start: bool,
+    /// The shape the state is drawn with, if it differs from a circle
+    shape: Option<&'static str>,
+}
+
+/// A transition in the diagram
+struct Edge {
+    /// The name of the state the transition starts from
+    from: String,
+    /// The name of the state the transition leads to
+    to: String,
+    /// The label of the transition
+    label: String,
+}
+
+/// Create the label of a transition
+fn label<Alphabet: fmt::Display>(read: &Alphabet, write: &Alphabet, mv: Move) -> String {
+    let mv = match mv {
+        Move::Left => "L",
+        Move::Stay => "S",
+        Move::Right => "R",
+    };
+
+    format!("{} → {}, {}", read, write, mv)
+}
+
+/// Write the diagram as a DOT graph, merging the labels of parallel transitions
+fn write_dot(nodes: Vec<Node>, edges: Vec<Edge>) -> String {
+    let mut dot = String::from("digraph {\n    rankdir=LR;\n    node [shape=circle];\n");
+
+    for node in nodes.iter() {
+        match node.shape {
+            Some(shape) => dot.push_str(&format!("    {} [shape={}];\n", quote(&node.name), shape)),
+            None => dot.push_str(&format!("    {};\n", quote(&node.name))),
+        }
+    }
+
+    if let Some(start) = nodes.iter().find(|node| node.start) {
+        dot.push_str("    __start [shape=point];\n");
+        dot.push_str(&format!("    __start -> {};\n", quote(&start.name)));
+    }
+
+    let mut merged: Vec<(String, String, Vec<String>)> = Vec::new();
+    for edge in edges {
+        match merged
+            .iter_mut()
+            .find(|(from, to, _)| *from == edge.from && *to == edge.to)
+        {
+            Some((_, _, labels)) => labels.push(edge.label),
+            None => merged.push((edge.from, edge.to, vec![edge.label])),
+        }
+    }
+
+    for (from, to, labels) in merged {
+        dot.push_str(&format!(
+            "    {} -> {} [label={}];\n",
+            quote(&from),
+            quote(&to),
+            quote(&labels.join("\n"))
+        ));
+    }
+
+    dot.push_str("}\n");
+    dot
+}
+
+/// Quote and escape a string as a DOT identifier
+fn quote(value: &str) -> String {
+    let escaped = value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n");
+    format!("\"{}\"", escaped)
+}
@@ -0,0 +1,25 @@
+use std::error;
+use std::fmt;
+
+/// The errors which can occur while operating a turing machine
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    /// The cursor went off the left side of a tape which is not two-way infinite
+    FellOffLeft,
+    /// No transition is defined for the current state and token
+    UndefinedTransition,
+    /// A machine or tape description is malformed or cannot be represented
+    Format(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::FellOffLeft => write!(f, "went off the left side of the tape"),
+            Error::UndefinedTransition => write!(f, "no transition defined"),
+            Error::Format(message) => write!(f, "invalid format: {}", message),
+        }
+    }
+}
+
+impl error::Error for Error {}
@@ -0,0 +1,127 @@
+use crate::{EndStates, Error, Move, TuringStates, TuringTape};
+
+/// A record of a single executed step of a turing machine
+#[derive(Debug, Clone, PartialEq)]
+pub struct Step<Alphabet, S> {
+    /// The index of the step, starting at zero
+    pub index: usize,
+    /// The state before the step
+    pub from: S,
+    /// The token read at the cursor
+    pub read: Alphabet,
+    /// The token written at the cursor
+    pub written: Alphabet,
+    /// The move of the cursor
+    pub mv: Move,
+    /// The state after the step
+    pub to: S,
+    /// The position of the cursor after the step, relative to the first cell of the tape at
+    /// creation
+    pub position: isize,
+}
+
+/// An iterator over the steps of a turing machine running on a tape
+///
+/// Created with [`TuringTape::execute`]. The iterator ends once one of the end states has been
+/// reached or a step fails.
+pub struct Execution<'a, Alphabet, S, E = Vec<S>> {
+    /// The tape the machine runs on
+    tape: &'a TuringTape<Alphabet>,
+    /// The current state
+    state: S,
+    /// The states in which the machine halts
+    end_states: E,
+    /// The index of the next step
+    index: usize,
+    /// The error which stopped the execution
+    error: Option<Error>,
+}
+
+impl<'a, Alphabet: Clone, S: TuringStates<Alphabet>, E: EndStates<S>>
+    Execution<'a, Alphabet, S, E>
+{
+    /// Fetch the current state
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    /// Take the current state
+    pub fn into_state(self) -> S {
+        self.state
+    }
+
+    /// Fetch the tape the machine runs on
+    pub fn tape(&self) -> &'a TuringTape<Alphabet> {
+        self.tape
+    }
+
+    /// Fetch the amount of steps taken
+    pub fn steps(&self) -> usize {
+        self.index
+    }
+
+    /// Fetch the error which stopped the execution, if any
+    pub fn error(&self) -> Option<&Error> {
+        self.error.as_ref()
+    }
+
+    /// Returns whether one of the end states has been reached
+    pub fn is_halted(&self) -> bool {
+        self.end_states.is_halting(&self.state)
+    }
+}
+
+impl<'a, Alphabet: Clone, S: TuringStates<Alphabet> + Clone, E: EndStates<S>> Iterator
+    for Execution<'a, Alphabet, S, E>
+{
+    type Item = Step<Alphabet, S>;
+
+    fn next(&mut self) -> Option<Step<Alphabet, S>> {
+        if self.error.is_some() || self.is_halted() {
+            return None;
+        }
+
+        let from = self.state.clone();
+        let read = self.tape.get_cursor();
+        let position = self.tape.position();
+
+        let mv = match self.tape.apply_step(&mut self.state) {
+            Ok((mv, _)) => mv,
+            Err(err) => {
+                self.error = Some(err);
+                return None;
+            }
+        };
+
+        let step = Step {
+            index: self.index,
+            from,
+            read,
+            written: self.tape.get_at(position),
+            mv,
+            to: self.state.clone(),
+            position: self.tape.position(),
+        };
+
+        self.index += 1;
+        Some(step)
+    }
+}
+
+impl<Alphabet: Clone> TuringTape<Alphabet> {
+    /// Create an iterator over the steps of the machine running from the start state, until one
+    /// of the end states has been reached.
+    pub fn execute<S: TuringStates<Alphabet>, E: EndStates<S>>(
+        &self,
+        start_state: S,
+        end_states: E,
+    ) -> Execution<'_, Alphabet, S, E> {
+        Execution {
+            tape: self,
+            state: start_state,
+            end_states,
+            index: 0,
+            error: None,
+        }
+    }
+}
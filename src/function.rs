@@ -0,0 +1,347 @@
+use crate::{EndStates, Error, RunOutcome, TuringStates, TuringTape};
+
+/// Converts an input value into the tokens put on the tape after the start token
+pub trait Encoder<Input, Alphabet> {
+    /// Encode an input value as tokens
+    fn encode(&self, input: Input) -> Vec<Alphabet>;
+}
+
+impl<Input, Alphabet, F: Fn(Input) -> Vec<Alphabet>> Encoder<Input, Alphabet> for F {
+    fn encode(&self, input: Input) -> Vec<Alphabet> {
+        self(input)
+    }
+}
+
+/// Reads an output value off the tape of a halted machine
+pub trait Decoder<Alphabet> {
+    /// The value read off the tape
+    type Output;
+
+    /// Decode the output value from a tape
+    fn decode(&self, tape: &TuringTape<Alphabet>) -> Result<Self::Output, Error>;
+}
+
+impl<Alphabet, Output, F> Decoder<Alphabet> for F
+where
+    F: Fn(&TuringTape<Alphabet>) -> Result<Output, Error>,
+{
+    type Output = Output;
+
+    fn decode(&self, tape: &TuringTape<Alphabet>) -> Result<Output, Error> {
+        self(tape)
+    }
+}
+
+/// The unary encoding, where `n` is written as `n` times the `one` token and multiple numbers
+/// are separated by the `separator` token
+///
+/// As a decoder it counts the `one` tokens from the cursor up to the first other token.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Unary<Alphabet> {
+    /// The token repeated to write a number
+    one: Alphabet,
+    /// The token put between numbers
+    separator: Alphabet,
+}
+
+impl<Alphabet> Unary<Alphabet> {
+    /// Initialize a new unary encoding
+    pub fn new(one: Alphabet, separator: Alphabet) -> Unary<Alphabet> {
+        Unary { one, separator }
+    }
+}
+
+impl<Alphabet: Clone> Unary<Alphabet> {
+    /// Encode numbers one after the other
+    fn encode_all(&self, numbers: &[usize]) -> Vec<Alphabet> {
+        let mut tokens = Vec::new();
+
+        for (index, &n) in numbers.iter().enumerate() {
+            if index > 0 {
+                tokens.push(self.separator.clone());
+            }
+            tokens.extend(std::iter::repeat_n(self.one.clone(), n));
+        }
+
+        tokens
+    }
+}
+
+impl<Alphabet: Clone> Encoder<usize, Alphabet> for Unary<Alphabet> {
+    fn encode(&self, input: usize) -> Vec<Alphabet> {
+        self.encode_all(&[input])
+    }
+}
+
+impl<Alphabet: Clone> Encoder<(usize, usize), Alphabet> for Unary<Alphabet> {
+    fn encode(&self, input: (usize, usize)) -> Vec<Alphabet> {
+        self.encode_all(&[input.0, input.1])
+    }
+}
+
+impl<Alphabet: Clone> Encoder<Vec<usize>, Alphabet> for Unary<Alphabet> {
+    fn encode(&self, input: Vec<usize>) -> Vec<Alphabet> {
+        self.encode_all(&input)
+    }
+}
+
+impl<Alphabet: PartialEq> Decoder<Alphabet> for Unary<Alphabet> {
+    type Output = usize;
+
+    fn decode(&self, tape: &TuringTape<Alphabet>) -> Result<usize, Error> {
+        let cells = tape.cells.borrow();
+
+        Ok(cells
+            .range(tape.cursor.get()..)
+            .take_while(|token| **token == self.one)
+            .count())
+    }
+}
+
+/// The binary encoding, where numbers are written most significant bit first with the `zero`
+/// and `one` tokens and multiple numbers are separated by the `separator` token
+///
+/// As a decoder it reads the `zero` and `one` tokens from the cursor up to the first other
+/// token.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Binary<Alphabet> {
+    /// The token for a 0 bit
+    zero: Alphabet,
+    /// The token for a 1 bit
+    one: Alphabet,
+    /// The token put between numbers
+    separator: Alphabet,
+}
+
+impl<Alphabet> Binary<Alphabet> {
+    /// Initialize a new binary encoding
+    pub fn new(zero: Alphabet, one: Alphabet, separator: Alphabet) -> Binary<Alphabet> {
+        Binary {
+            zero,
+            one,
+            separator,
+        }
+    }
+}
+
+impl<Alphabet: Clone> Binary<Alphabet> {
+    /// Encode numbers one after the other
+    fn encode_all(&self, numbers: &[usize]) -> Vec<Alphabet> {
+        let mut tokens = Vec::new();
+
+        for (index, &n) in numbers.iter().enumerate() {
+            if index > 0 {
+                tokens.push(self.separator.clone());
+            }
+
+            let bits = (usize::BITS - n.leading_zeros()).max(1);
+            tokens.extend((0..bits).rev().map(|bit| {
+                if n >> bit & 1 == 1 {
+                    self.one.clone()
+                } else {
+                    self.zero.clone()
+                }
+            }));
+        }
+
+        tokens
+    }
+}
+
+impl<Alphabet: Clone> Encoder<usize, Alphabet> for Binary<Alphabet> {
+    fn encode(&self, input: usize) -> Vec<Alphabet> {
+        self.encode_all(&[input])
+    }
+}
+
+impl<Alphabet: Clone> Encoder<(usize, usize), Alphabet> for Binary<Alphabet> {
+    fn encode(&self, input: (usize, usize)) -> Vec<Alphabet> {
+        self.encode_all(&[input.0, input.1])
+    }
+}
+
+impl<Alphabet: Clone> Encoder<Vec<usize>, Alphabet> for Binary<Alphabet> {
+    fn encode(&self, input: Vec<usize>) -> Vec<Alphabet> {
+        self.encode_all(&input)
+    }
+}
+
+impl<Alphabet: PartialEq> Decoder<Alphabet> for Binary<Alphabet> {
+    type Output = usize;
+
+    fn decode(&self, tape: &TuringTape<Alphabet>) -> Result<usize, Error> {
+        let cells = tape.cells.borrow();
+        let mut value: usize = 0;
+        let mut bits = 0;
+
+        for token in cells.range(tape.cursor.get()..) {
+            let bit = if *token == self.zero {
+                0
+            } else if *token == self.one {
+                1
+            } else {
+                break;
+            };
+
+            value = value
+                .checked_mul(2)
+                .map(|value| value + bit)
+                .ok_or_else(|| Error::Format("binary number does not fit a usize".to_string()))?;
+            bits += 1;
+        }
+
+        if bits == 0 {
+            return Err(Error::Format("no binary number at the cursor".to_string()));
+        }
+
+        Ok(value)
+    }
+}
+
+/// A turing machine computing a function, paired with the encoding of its input and the
+/// decoding of its output
+///
+/// ```
+/// use turingmachine_rs::{ComputableFunction, Move, RunOutcome, TransitionTable, Unary};
+///
+/// // Adds two unary numbers by moving the second one to the left
+/// let table = TransitionTable::new("start")
+///     .transition("start", '_', "first", '_', Move::Right)
+///     .transition("first", '1', "first", '1', Move::Right)
+///     .transition("first", '_', "second", '1', Move::Right)
+///     .transition("second", '1', "second", '1', Move::Right)
+///     .transition("second", '_', "erase", '_', Move::Left)
+///     .transition("erase", '1', "rewind", '_', Move::Left)
+///     .transition("rewind", '1', "rewind", '1', Move::Left)
+///     .transition("rewind", '_', "done", '_', Move::Right)
+///     .accept("done");
+///
+/// let add = ComputableFunction::new(
+///     table.start(),
+///     table.end_states(),
+///     '_',
+///     '_',
+///     Unary::new('1', '_'),
+///     Unary::new('1', '_'),
+/// );
+///
+/// assert_eq!(add.compute((2, 3)), Ok(5));
+/// assert!(matches!(
+///     add.compute_limited((2, 3), 5),
+///     Err(RunOutcome::OutOfSteps { steps: 5, .. })
+/// ));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ComputableFunction<Alphabet, S, E, D, H = Vec<S>> {
+    /// The state to start in
+    start: S,
+    /// The states in which the machine halts
+    end_states: H,
+    /// The token put at empty tape cells
+    empty: Alphabet,
+    /// The token put in the first cell, before the input
+    start_token: Alphabet,
+    /// The encoding of the input
+    encoder: E,
+    /// The decoding of the output
+    decoder: D,
+}
+
+impl<Alphabet, S, E, D, H> ComputableFunction<Alphabet, S, E, D, H>
+where
+    Alphabet: Clone,
+    S: TuringStates<Alphabet> + Clone,
+    D: Decoder<Alphabet>,
+    H: EndStates<S>,
+{
+    /// Initialize a new ComputableFunction with:
+    ///
+    /// - __start:__ The state to start in
+    /// - __end_states:__ The states in which the machine halts
+    /// - __empty:__ The token put at empty tape cells
+    /// - __start_token:__ The token put in the first cell, before the input
+    /// - __encoder:__ Converts an input into the tokens after the start token
+    /// - __decoder:__ Reads the output off the tape once the machine halted
+    pub fn new(
+        start: S,
+        end_states: H,
+        empty: Alphabet,
+        start_token: Alphabet,
+        encoder: E,
+        decoder: D,
+    ) -> ComputableFunction<Alphabet, S, E, D, H> {
+        ComputableFunction {
+            start,
+            end_states,
+            empty,
+            start_token,
+            encoder,
+            decoder,
+        }
+    }
+
+    /// Create the tape the machine starts with for an input
+    pub fn tape<Input>(&self, input: Input) -> TuringTape<Alphabet>
+    where
+        E: Encoder<Input, Alphabet>,
+    {
+        TuringTape::new(
+            self.empty.clone(),
+            self.start_token.clone(),
+            self.encoder.encode(input),
+        )
+    }
+
+    /// Read the output off the tape of a halted machine
+    pub fn decode(&self, tape: &TuringTape<Alphabet>) -> Result<D::Output, Error> {
+        self.decoder.decode(tape)
+    }
+
+    /// Run the machine on an input until it halts and return its output
+    ///
+    /// Will return an error if the machine cannot continue before reaching an end state or if
+    /// the output cannot be decoded. Use [`ComputableFunction::compute_limited`] for machines
+    /// which might not halt.
+    pub fn compute<Input>(&self, input: Input) -> Result<D::Output, Error>
+    where
+        E: Encoder<Input, Alphabet>,
+    {
+        let tape = self.tape(input);
+        tape.try_run_states(self.start.clone(), |state: &S| {
+            self.end_states.is_halting(state)
+        })?;
+
+        self.decode(&tape)
+    }
+
+    /// Run the machine on an input for at most `max_steps` steps and return its output
+    ///
+    /// Will return how the run ended if the machine did not halt, or
+    /// [`RunOutcome::Failed`] with the end state if the output cannot be decoded.
+    pub fn compute_limited<Input>(
+        &self,
+        input: Input,
+        max_steps: usize,
+    ) -> Result<D::Output, RunOutcome<S>>
+    where
+        E: Encoder<Input, Alphabet>,
+    {
+        let tape = self.tape(input);
+        let outcome = tape.run_states_limited(
+            self.start.clone(),
+            |state: &S| self.end_states.is_halting(state),
+            max_steps,
+        );
+
+        match outcome {
+            RunOutcome::Halted { state, steps } => {
+                self.decode(&tape).map_err(|error| RunOutcome::Failed {
+                    state,
+                    steps,
+                    error,
+                })
+            }
+            outcome => Err(outcome),
+        }
+    }
+}
@@ -0,0 +1,62 @@
+/// The states in which a turing machine halts
+///
+/// Implemented for lists of states and references to them, halting in any of the listed
+/// states, and for predicates on states, so halting can depend on the data carried by a state:
+///
+/// ```
+/// use turingmachine_rs::{Move, TuringStates, TuringTape};
+///
+/// #[derive(Clone, PartialEq, Debug)]
+/// struct Counting(usize);
+///
+/// impl TuringStates<char> for Counting {
+///     fn step(&self, token: char) -> (Self, char, Move) {
+///         (Counting(self.0 + 1), token, Move::Right)
+///     }
+/// }
+///
+/// let tape = TuringTape::new('_', '_', vec![]);
+/// let end_state = tape.run_states(Counting(0), |state: &Counting| state.0 > 5);
+///
+/// assert_eq!(end_state, Counting(6));
+/// ```
+pub trait EndStates<S> {
+    /// Returns whether the machine halts in a state
+    fn is_halting(&self, state: &S) -> bool;
+}
+
+impl<S: PartialEq> EndStates<S> for Vec<S> {
+    fn is_halting(&self, state: &S) -> bool {
+        self.contains(state)
+    }
+}
+
+impl<S: PartialEq> EndStates<S> for &[S] {
+    fn is_halting(&self, state: &S) -> bool {
+        self.contains(state)
+    }
+}
+
+impl<S: PartialEq> EndStates<S> for &Vec<S> {
+    fn is_halting(&self, state: &S) -> bool {
+        self.contains(state)
+    }
+}
+
+impl<S: PartialEq, const N: usize> EndStates<S> for [S; N] {
+    fn is_halting(&self, state: &S) -> bool {
+        self.contains(state)
+    }
+}
+
+impl<S: PartialEq, const N: usize> EndStates<S> for &[S; N] {
+    fn is_halting(&self, state: &S) -> bool {
+        self.contains(state)
+    }
+}
+
+impl<S, F: Fn(&S) -> bool> EndStates<S> for F {
+    fn is_halting(&self, state: &S) -> bool {
+        self(state)
+    }
+}
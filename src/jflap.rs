@@ -0,0 +1,341 @@
+//! The `.jff` files of [JFLAP](https://www.jflap.org) describing turing machines
+//!
+//! ```xml
+//! <structure>
+//!     <type>turing</type>
+//!     <automaton>
+//!         <state id="0" name="q0"><initial/></state>
+//!         <state id="1" name="q1"><final/></state>
+//!         <transition>
+//!             <from>0</from><to>1</to>
+//!             <read>a</read><write/><move>R</move>
+//!         </transition>
+//!     </automaton>
+//! </structure>
+//! ```
+//!
+//! Machines with multiple tapes list the number of tapes in a `<tapes>` element and have a
+//! `<read>`, `<write>` and `<move>` element for every tape, marked with a `tape` attribute. An
+//! empty `<read/>` or `<write/>` element is the blank symbol, which is represented by
+//! [`JFLAP_BLANK`].
+
+use roxmltree::{Document, Node};
+
+use crate::{Error, Move, MultiTapeTable, TransitionTable, TuringTape};
+
+/// The symbol used for blank cells of machines loaded from JFLAP, the square JFLAP displays
+pub const JFLAP_BLANK: char = '□';
+
+impl TransitionTable<String, char> {
+    /// Load a single tape machine from the contents of a JFLAP `.jff` file
+    ///
+    /// States are named by their JFLAP names and final states are accepting end states. Will
+    /// return an error for malformed files and features which cannot be represented, such as
+    /// building blocks, the `~` wildcard or nondeterministic transitions.
+    pub fn from_jff(xml: &str) -> Result<TransitionTable<String, char>, Error> {
+        let machine = parse(xml, 1)?;
+
+        let table = machine.transitions.into_iter().fold(
+            TransitionTable::new(machine.initial),
+            |table, transition| {
+                table.transition(
+                    transition.from,
+                    transition.read[0],
+                    transition.to,
+                    transition.write[0],
+                    transition.moves[0],
+                )
+            },
+        );
+
+        Ok(machine
+            .finals
+            .into_iter()
+            .fold(table, |table, state| table.accept(state)))
+    }
+}
+
+impl<const K: usize> MultiTapeTable<String, char, K> {
+    /// Load a machine with `K` tapes from the contents of a JFLAP `.jff` file
+    ///
+    /// States are named by their JFLAP names and final states are accepting end states. Will
+    /// return an error for malformed files, files with a different number of tapes and features
+    /// which cannot be represented, such as building blocks, the `~` wildcard or
+    /// nondeterministic transitions.
+    pub fn from_jff(xml: &str) -> Result<MultiTapeTable<String, char, K>, Error> {
+        let machine = parse(xml, K)?;
+
+        let table = machine.transitions.into_iter().fold(
+            MultiTapeTable::new(machine.initial),
+            |table, transition| {
+                let Transition {
+                    from,
+                    to,
+                    read,
+                    write,
+                    moves,
+                } = transition;
+
+                table.transition(
+                    from,
+                    std::array::from_fn(|tape| read[tape]),
+                    to,
+                    std::array::from_fn(|tape| write[tape]),
+                    std::array::from_fn(|tape| moves[tape]),
+                )
+            },
+        );
+
+        Ok(machine
+            .finals
+            .into_iter()
+            .fold(table, |table, state| table.accept(state)))
+    }
+}
+
+impl TuringTape<char> {
+    /// Initialize a new TuringTape like JFLAP does, two-way infinite with the cursor on the
+    /// first symbol of the input and [`JFLAP_BLANK`] at empty cells
+    pub fn from_jflap_input(input: &str) -> TuringTape<char> {
+        let mut input = input.chars();
+        TuringTape::new_two_way(
+            JFLAP_BLANK,
+            input.next().unwrap_or(JFLAP_BLANK),
+            input.collect(),
+        )
+    }
+}
+
+/// A machine as described in a JFLAP file
+struct Machine {
+    /// The name of the initial state
+    initial: String,
+    /// The names of the final states
+    finals: Vec<String>,
+    /// The transitions between the states
+    transitions: Vec<Transition>,
+}
+
+/// A transition as described in a JFLAP file, with the symbols and moves for every tape
+struct Transition {
+    /// The name of the state the transition starts from
+    from: String,
+    /// The name of the state the transition leads to
+    to: String,
+    /// The symbols read
+    read: Vec<char>,
+    /// The symbols written
+    write: Vec<char>,
+    /// The moves of the cursors
+    moves: Vec<Move>,
+}
+
+/// Parse a JFLAP file describing a turing machine with `tapes` tapes
+fn parse(xml: &str, tapes: usize) -> Result<Machine, Error> {
+    let document = Document::parse(xml).map_err(|err| Error::Format(err.to_string()))?;
+    let structure = document.root_element();
+    if !structure.has_tag_name("structure") {
+        return Err(Error::Format(
+            "expected a <structure> root element".to_string(),
+        ));
+    }
+
+    let kind = child(structure, "type").map(text).unwrap_or_default();
+    if kind != "turing" {
+        return Err(Error::Format(format!(
+            "unsupported machine type '{}'",
+            kind
+        )));
+    }
+
+    let file_tapes = match child(structure, "tapes") {
+        Some(node) => text(node)
+            .trim()
+            .parse()
+            .map_err(|_| Error::Format("invalid number of tapes".to_string()))?,
+        None => 1,
+    };
+    if file_tapes != tapes {
+        return Err(Error::Format(format!(
+            "expected a machine with {} tapes, found {}",
+            tapes, file_tapes
+        )));
+    }
+
+    let automaton = child(structure, "automaton")
+        .ok_or_else(|| Error::Format("missing <automaton>".to_string()))?;
+
+    // The states by their id, with their name
+    let mut states = Vec::new();
+    let mut initial = None;
+    let mut finals = Vec::new();
+    let mut transitions = Vec::new();
+
+    for node in automaton.children().filter(Node::is_element) {
+        match node.tag_name().name() {
+            "state" => {
+                let id = attribute(node, "id")?;
+                let name = node.attribute("name").unwrap_or(id).to_string();
+                if states.iter().any(|(_, other)| *other == name) {
+                    return Err(Error::Format(format!("duplicate state name '{}'", name)));
+                }
+
+                if child(node, "initial").is_some() {
+                    if initial.is_some() {
+                        return Err(Error::Format("multiple initial states".to_string()));
+                    }
+                    initial = Some(name.clone());
+                }
+                if child(node, "final").is_some() {
+                    finals.push(name.clone());
+                }
+
+                states.push((id, name));
+            }
+            "transition" => transitions.push(node),
+            "note" => {}
+            "block" => {
+                return Err(Error::Format(
+                    "building blocks are not supported".to_string(),
+                ))
+            }
+            tag => return Err(Error::Format(format!("unsupported element <{}>", tag))),
+        }
+    }
+
+    let state_name = |node: Node| -> Result<String, Error> {
+        let id = text(node);
+        states
+            .iter()
+            .find(|(other, _)| *other == id.trim())
+            .map(|(_, name)| name.clone())
+            .ok_or_else(|| Error::Format(format!("unknown state id '{}'", id)))
+    };
+
+    let mut parsed: Vec<Transition> = Vec::with_capacity(transitions.len());
+    for node in transitions {
+        let transition = Transition {
+            from: state_name(required_child(node, "from")?)?,
+            to: state_name(required_child(node, "to")?)?,
+            read: per_tape(node, "read", tapes)?
+                .into_iter()
+                .map(symbol)
+                .collect::<Result<_, _>>()?,
+            write: per_tape(node, "write", tapes)?
+                .into_iter()
+                .map(symbol)
+                .collect::<Result<_, _>>()?,
+            moves: per_tape(node, "move", tapes)?
+                .into_iter()
+                .map(parse_move)
+                .collect::<Result<_, _>>()?,
+        };
+
+        if parsed
+            .iter()
+            .any(|other| other.from == transition.from && other.read == transition.read)
+        {
+            return Err(Error::Format(format!(
+                "nondeterministic transitions from state '{}'",
+                transition.from
+            )));
+        }
+
+        parsed.push(transition);
+    }
+
+    Ok(Machine {
+        initial: initial.ok_or_else(|| Error::Format("missing initial state".to_string()))?,
+        finals,
+        transitions: parsed,
+    })
+}
+
+/// Collect the elements with a tag for every tape, in the order of the tapes
+fn per_tape<'a, 'input>(
+    node: Node<'a, 'input>,
+    tag: &str,
+    tapes: usize,
+) -> Result<Vec<Node<'a, 'input>>, Error> {
+    let mut elements = vec![None; tapes];
+
+    for element in node.children().filter(|child| child.has_tag_name(tag)) {
+        // Single tape machines leave out the tape attribute
+        let tape = match element.attribute("tape") {
+            Some(tape) => tape
+                .parse::<usize>()
+                .ok()
+                .filter(|tape| (1..=tapes).contains(tape))
+                .ok_or_else(|| Error::Format(format!("invalid tape '{}'", tape)))?,
+            None if tapes == 1 => 1,
+            None => return Err(Error::Format(format!("<{}> without a tape", tag))),
+        };
+
+        if elements[tape - 1].replace(element).is_some() {
+            return Err(Error::Format(format!(
+                "multiple <{}> for tape {}",
+                tag, tape
+            )));
+        }
+    }
+
+    elements
+        .into_iter()
+        .enumerate()
+        .map(|(tape, element)| {
+            element.ok_or_else(|| Error::Format(format!("missing <{}> for tape {}", tag, tape + 1)))
+        })
+        .collect()
+}
+
+/// Parse a read or written symbol, where an empty element is the blank symbol
+fn symbol(node: Node) -> Result<char, Error> {
+    let text = text(node);
+    let mut chars = text.chars();
+
+    match (chars.next(), chars.next()) {
+        (None, _) => Ok(JFLAP_BLANK),
+        (Some('~'), None) => Err(Error::Format(
+            "the '~' wildcard is not supported".to_string(),
+        )),
+        (Some(symbol), None) => Ok(symbol),
+        _ => Err(Error::Format(format!(
+            "symbol '{}' is not a single character",
+            text
+        ))),
+    }
+}
+
+/// Parse the `L`, `S` and `R` moves
+fn parse_move(node: Node) -> Result<Move, Error> {
+    match text(node).trim() {
+        "L" => Ok(Move::Left),
+        "S" => Ok(Move::Stay),
+        "R" => Ok(Move::Right),
+        mv => Err(Error::Format(format!("unsupported move '{}'", mv))),
+    }
+}
+
+/// Find the first child element with a tag
+fn child<'a, 'input>(node: Node<'a, 'input>, tag: &str) -> Option<Node<'a, 'input>> {
+    node.children().find(|child| child.has_tag_name(tag))
+}
+
+/// Find the first child element with a tag, or return an error
+fn required_child<'a, 'input>(
+    node: Node<'a, 'input>,
+    tag: &str,
+) -> Result<Node<'a, 'input>, Error> {
+    child(node, tag).ok_or_else(|| Error::Format(format!("missing <{}>", tag)))
+}
+
+/// Fetch an attribute, or return an error
+fn attribute<'a>(node: Node<'a, '_>, name: &str) -> Result<&'a str, Error> {
+    node.attribute(name)
+        .ok_or_else(|| Error::Format(format!("missing '{}' attribute", name)))
+}
+
+/// Fetch the text contained in an element
+fn text<'a>(node: Node<'a, '_>) -> &'a str {
+    node.text().unwrap_or("")
+}
@@ -1,94 +1,83 @@
 //! A simulation crate for Turing Machines
 #![warn(missing_docs)]
 
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, VecDeque};
 use std::fmt;
-use std::rc::{Rc, Weak};
+use std::hash::Hash;
 
-/// A struct representing a node in a linked list
-#[derive(Clone)]
-struct Node<Alphabet> {
-    /// The previous node
-    prev: Option<Rc<Node<Alphabet>>>,
-    /// The next node
-    next: RefCell<Option<Weak<Node<Alphabet>>>>,
-    /// The data contained in the node
-    data: RefCell<Alphabet>,
-}
-
-impl<Alphabet: Clone> Node<Alphabet> {
-    /// Create a new node with a data and possibly a previous node
-    fn new(data: Alphabet, prev: Option<Rc<Node<Alphabet>>>) -> Node<Alphabet> {
-        Node {
-            prev,
-            next: RefCell::new(None),
-            data: RefCell::new(data),
-        }
-    }
-
-    /// Fetch previous node
-    fn prev(&self) -> Option<Rc<Node<Alphabet>>> {
-        self.prev.clone()
-    }
-
-    /// Fetch next node
-    fn next(&self) -> Option<Weak<Node<Alphabet>>> {
-        self.next.borrow().clone()
-    }
-
-    /// Replace next with possible new next node
-    fn replace_next(
-        &self,
-        new_value: Option<Weak<Node<Alphabet>>>,
-    ) -> Option<Weak<Node<Alphabet>>> {
-        self.next.replace(new_value)
-    }
-
-    /// Fetch the data contained in node
-    fn get(&self) -> Alphabet {
-        self.data.borrow().clone()
-    }
+mod bbchallenge;
+mod decider;
+mod dot;
+mod error;
+mod execution;
+mod function;
+mod halting;
+#[cfg(feature = "jflap")]
+mod jflap;
+mod macros;
+mod multitape;
+mod nondeterministic;
+mod observer;
+mod recording;
+mod snapshot;
+mod stats;
+mod symbol;
+mod table;
+mod text;
+#[cfg(feature = "yaml")]
+mod yaml;
 
-    /// Replace the data contained in the node
-    fn replace(&self, new_value: Alphabet) -> Alphabet {
-        self.data.replace(new_value)
-    }
-}
+pub use decider::{Decider, Recognizer, Verdict};
+pub use dot::states_to_dot;
+pub use error::Error;
+pub use execution::{Execution, Step};
+pub use function::{Binary, ComputableFunction, Decoder, Encoder, Unary};
+pub use halting::EndStates;
+#[cfg(feature = "jflap")]
+pub use jflap::JFLAP_BLANK;
+pub use multitape::{MultiTapeMachine, MultiTapeStates, MultiTapeTable, MultiTapeTableState};
+pub use nondeterministic::{Computation, NondeterministicStates, SearchOutcome};
+pub use observer::{MultiTapeObserver, Observer, PrintObserver, StepCounter};
+pub use recording::Recording;
+pub use snapshot::TapeSnapshot;
+pub use stats::RunStats;
+pub use symbol::TapeSymbol;
+pub use table::{BoundState, Table, TableState, TransitionTable};
+#[cfg(feature = "derive")]
+pub use turingmachine_rs_derive::TapeSymbol;
 
 /// A possibly theorically infinite TuringTape
+///
+/// Cloning a tape copies all its cells, so a computation can be branched by running on a clone.
+/// Use [`TuringTape::snapshot`] and [`TuringTape::restore`] to go back to an earlier point.
+#[derive(Clone)]
 pub struct TuringTape<Alphabet> {
     /// The alphabet token put at empty spaces
     empty: Alphabet,
-    /// The last node currently saved
-    last: RefCell<Rc<Node<Alphabet>>>,
-    /// The cursor point
-    cursor: RefCell<Rc<Node<Alphabet>>>,
+    /// The cells currently saved, from the leftmost to the rightmost
+    cells: RefCell<VecDeque<Alphabet>>,
+    /// The index of the cursor within the cells
+    cursor: Cell<usize>,
+    /// The index of the first cell at creation within the cells
+    origin: Cell<usize>,
+    /// Whether the tape grows to the left instead of ending at the first cell
+    two_way: bool,
 }
 
-impl<Alphabet: fmt::Display + Clone> fmt::Display for TuringTape<Alphabet> {
+impl<Alphabet: fmt::Display> fmt::Display for TuringTape<Alphabet> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "|")?;
-        let mut s = String::new();
 
-        let mut head: Rc<Node<Alphabet>> = self.last.borrow().clone();
-
-        loop {
-            let backup = s.clone();
-            if Rc::ptr_eq(&self.cursor.borrow(), &head) {
-                s = format!("> {} <|", head.get());
+        for (index, token) in self.cells.borrow().iter().enumerate() {
+            if index == self.cursor.get() {
+                write!(f, "> {} <|", token)?;
             } else {
-                s = format!("  {}  |", head.get());
-            }
-            s.push_str(&backup);
-
-            if let Some(new_head) = head.prev.clone() {
-                head = new_head;
-            } else {
-                break;
+                write!(f, "  {}  |", token)?;
             }
         }
 
-        write!(f, "{}", s)
+        Ok(())
     }
 }
 
@@ -99,125 +88,475 @@ impl<Alphabet: Clone> TuringTape<Alphabet> {
     /// - __start:__ The token put in the first cell
     /// - __initial:__ An vector of tokens to be put after the start token
     pub fn new(empty: Alphabet, start: Alphabet, initial: Vec<Alphabet>) -> TuringTape<Alphabet> {
-        let fst_node = Rc::new(Node::new(start, None));
-        let tape = TuringTape {
-            empty,
-            last: RefCell::new(fst_node.clone()),
-            cursor: RefCell::new(fst_node),
-        };
+        let mut cells = VecDeque::with_capacity(initial.len() + 1);
+        cells.push_back(start);
+        cells.extend(initial);
 
-        initial.into_iter().for_each(|token| {
-            tape.append(token);
-        });
+        TuringTape {
+            empty,
+            cells: RefCell::new(cells),
+            cursor: Cell::new(0),
+            origin: Cell::new(0),
+            two_way: false,
+        }
+    }
 
+    /// Initialize a new two-way infinite TuringTape with:
+    ///
+    /// - __empty:__ The token put at empty tape cells
+    /// - __start:__ The token put in the first cell
+    /// - __initial:__ An vector of tokens to be put after the start token
+    ///
+    /// Contrary to [`TuringTape::new`], stepping left of the first cell will grow the tape to
+    /// the left with empty tokens.
+    pub fn new_two_way(
+        empty: Alphabet,
+        start: Alphabet,
+        initial: Vec<Alphabet>,
+    ) -> TuringTape<Alphabet> {
+        let mut tape = TuringTape::new(empty, start, initial);
+        tape.two_way = true;
         tape
     }
 
-    /// Append a new token to the turing tape
-    fn append(&self, token: Alphabet) -> Rc<Node<Alphabet>> {
-        let new_node = Rc::new(Node::new(token, Some(self.last.borrow().clone())));
-        self.last
-            .borrow()
-            .replace_next(Some(Rc::downgrade(&new_node)));
-        self.last.replace(new_node.clone());
-        new_node
+    /// Place the cursor at an offset from the first cell, growing the tape to the right with
+    /// empty tokens if the offset lies beyond the initial cells
+    ///
+    /// ```
+    /// use turingmachine_rs::TuringTape;
+    ///
+    /// let tape = TuringTape::new('_', 'a', vec!['b', 'c']).with_cursor(1);
+    /// assert_eq!(tape.get_cursor(), 'b');
+    /// assert_eq!(tape.position(), 1);
+    /// ```
+    pub fn with_cursor(self, offset: usize) -> TuringTape<Alphabet> {
+        let cursor = self.origin.get() + offset;
+
+        {
+            let mut cells = self.cells.borrow_mut();
+            while cells.len() <= cursor {
+                cells.push_back(self.empty.clone());
+            }
+        }
+
+        self.cursor.set(cursor);
+        self
+    }
+
+    /// Returns whether the tape grows to the left when stepping off the first cell
+    pub fn is_two_way(&self) -> bool {
+        self.two_way
     }
 
     /// Fetch the token at the cursor
     pub fn get_cursor(&self) -> Alphabet {
-        self.cursor.borrow().clone().get()
+        self.cells.borrow()[self.cursor.get()].clone()
     }
 
     /// Set the token at the cursor and return the old token
     pub fn set_cursor(&self, value: Alphabet) -> Alphabet {
-        self.cursor.borrow().clone().replace(value)
+        std::mem::replace(&mut self.cells.borrow_mut()[self.cursor.get()], value)
     }
 
     /// Make the cursor go one cell to the right
     pub fn step_right(&self) -> Alphabet {
-        let new_cursor = match self.cursor.borrow().clone().next() {
-            Some(next) => next.clone().upgrade().expect("Unable to upgrade"),
-            None => self.append(self.empty.clone()),
-        };
+        let new_cursor = self.cursor.get() + 1;
+
+        let mut cells = self.cells.borrow_mut();
+        if new_cursor == cells.len() {
+            cells.push_back(self.empty.clone());
+        }
 
-        self.cursor.replace(new_cursor);
-        self.get_cursor()
+        self.cursor.set(new_cursor);
+        cells[new_cursor].clone()
     }
 
     /// Make the cursor go one cell to the left
     ///
-    /// Will panic if one goes off the tape.
+    /// Will panic if one goes off a tape which is not two-way infinite.
     pub fn step_left(&self) -> Alphabet {
-        let new_cursor = match self.cursor.borrow().clone().prev() {
-            Some(prev) => prev.clone(),
-            None => panic!("Went left side of the tape!"),
+        let mut cells = self.cells.borrow_mut();
+        match self.cursor.get() {
+            0 if self.two_way => {
+                cells.push_front(self.empty.clone());
+                self.origin.set(self.origin.get() + 1);
+            }
+            0 => panic!("Went left side of the tape!"),
+            cursor => self.cursor.set(cursor - 1),
+        }
+
+        cells[self.cursor.get()].clone()
+    }
+
+    /// Make the cursor go one cell to the left
+    ///
+    /// Will return an error instead of panicking if one goes off a tape which is not two-way
+    /// infinite.
+    pub fn try_step_left(&self) -> Result<Alphabet, Error> {
+        if self.can_step_left() {
+            Ok(self.step_left())
+        } else {
+            Err(Error::FellOffLeft)
+        }
+    }
+
+    /// Fetch the position of the cursor relative to the first cell at creation
+    ///
+    /// The position is negative if the cursor is left of the first cell of a two-way infinite
+    /// tape.
+    pub fn position(&self) -> isize {
+        self.cursor.get() as isize - self.origin.get() as isize
+    }
+
+    /// Fetch the token at a position relative to the first cell at creation
+    ///
+    /// Will panic if the position is outside of the saved cells.
+    fn get_at(&self, position: isize) -> Alphabet {
+        let index = self.origin.get() as isize + position;
+        self.cells.borrow()[index as usize].clone()
+    }
+
+    /// Returns whether the cursor can go one cell to the left without going off the tape
+    fn can_step_left(&self) -> bool {
+        self.two_way || self.cursor.get() > 0
+    }
+
+    /// Move the cursor according to the given movement direction.
+    /// Will return whether a cell was added to the tape.
+    fn move_cursor(&self, mv: Move) -> bool {
+        let len = self.cells.borrow().len();
+
+        match mv {
+            Move::Left => {
+                self.step_left();
+            }
+            Move::Stay => {}
+            Move::Right => {
+                self.step_right();
+            }
         };
 
-        self.cursor.replace(new_cursor);
-        self.get_cursor()
+        self.cells.borrow().len() > len
     }
 
-    /// Runs from start state until one of the end states has been reached.
-    /// Will return the end state.
-    pub fn run_states<S: TuringStates<Alphabet> + PartialEq>(
+    /// Undo a move of the cursor, removing the cell it added to the tape if `grew` is set
+    fn undo_move(&self, mv: Move, grew: bool) {
+        let mut cells = self.cells.borrow_mut();
+
+        match mv {
+            Move::Left if grew => {
+                cells.pop_front();
+                self.origin.set(self.origin.get() - 1);
+            }
+            Move::Left => self.cursor.set(self.cursor.get() + 1),
+            Move::Stay => {}
+            Move::Right => {
+                self.cursor.set(self.cursor.get() - 1);
+                if grew {
+                    cells.pop_back();
+                }
+            }
+        }
+    }
+
+    /// Execute one step of the machine in `state` on this tape.
+    /// Will return the move of the cursor and whether a cell was added to the tape.
+    ///
+    /// The state and the tape are left untouched if the step cannot be executed.
+    fn apply_step<S: TuringStates<Alphabet>>(&self, state: &mut S) -> Result<(Move, bool), Error> {
+        let (next, replace, mv) = state.try_step(self.get_cursor())?;
+
+        if matches!(mv, Move::Left) && !self.can_step_left() {
+            return Err(Error::FellOffLeft);
+        }
+
+        *state = next;
+        self.set_cursor(replace);
+
+        Ok((mv, self.move_cursor(mv)))
+    }
+
+    /// Runs from start state until one of the end states has been reached or no transition is
+    /// defined for the current state and token.
+    /// Will return the state the machine halted in.
+    ///
+    /// Will panic if the cursor goes off the left side of a tape which is not two-way infinite.
+    pub fn run_states<S: TuringStates<Alphabet> + PartialEq, E: EndStates<S>>(
         &self,
         mut start_state: S,
-        end_states: Vec<S>,
+        end_states: E,
     ) -> S {
-        while !end_states.contains(&start_state) {
-            start_state.internal_step(self);
+        while !end_states.is_halting(&start_state) {
+            match start_state.try_internal_step(self) {
+                Ok(()) => {}
+                // A partial transition function halts the machine where it is not defined
+                Err(Error::UndefinedTransition) => break,
+                Err(error) => panic!("{}", error),
+            }
         }
 
         start_state
     }
+
+    /// Runs from start state until one of the end states has been reached.
+    /// Will return the end state, or the error which stopped the run.
+    pub fn try_run_states<S: TuringStates<Alphabet>, E: EndStates<S>>(
+        &self,
+        mut start_state: S,
+        end_states: E,
+    ) -> Result<S, Error> {
+        while !end_states.is_halting(&start_state) {
+            start_state.try_internal_step(self)?;
+        }
+
+        Ok(start_state)
+    }
+
+    /// Runs from start state until one of the end states has been reached or `max_steps` steps
+    /// have been taken. Will return how the run ended.
+    ///
+    /// Contrary to [`TuringTape::run_states`], this will not panic when the cursor goes off the
+    /// left side of the tape. The transition leading off the tape is then not executed.
+    pub fn run_states_limited<S: TuringStates<Alphabet>, E: EndStates<S>>(
+        &self,
+        state: S,
+        end_states: E,
+        max_steps: usize,
+    ) -> RunOutcome<S> {
+        run_limited(state, end_states, max_steps, |state| {
+            state.try_internal_step(self)
+        })
+    }
+}
+
+impl<Alphabet: Clone + Eq + Hash> TuringTape<Alphabet> {
+    /// Runs from start state until one of the end states has been reached, `max_steps` steps
+    /// have been taken or the machine ends up in a configuration it has been in before. Will
+    /// return how the run ended.
+    ///
+    /// Every configuration (state, tape contents and cursor position) is remembered, which makes
+    /// this a lot more memory intensive than [`TuringTape::run_states_limited`]. Empty cells
+    /// around the contents are ignored, so merely moving onto new cells does not make a
+    /// configuration differ.
+    pub fn run_states_detect_loops<
+        S: TuringStates<Alphabet> + Clone + Eq + Hash,
+        E: EndStates<S>,
+    >(
+        &self,
+        mut state: S,
+        end_states: E,
+        max_steps: usize,
+    ) -> RunOutcome<S> {
+        let mut seen = HashMap::new();
+        let mut steps = 0;
+
+        while !end_states.is_halting(&state) {
+            if let Some(first_seen) = seen.insert((state.clone(), self.configuration()), steps) {
+                return RunOutcome::Loops {
+                    state,
+                    steps,
+                    cycle_length: steps - first_seen,
+                };
+            }
+
+            if steps == max_steps {
+                return RunOutcome::OutOfSteps { state, steps };
+            }
+
+            match state.try_internal_step(self) {
+                Ok(()) => steps += 1,
+                Err(error) => return RunOutcome::from_error(state, steps, error),
+            }
+        }
+
+        RunOutcome::Halted { state, steps }
+    }
+
+    /// Fetch the tape contents without the empty cells around them, and the cursor position
+    /// relative to the first of those cells
+    ///
+    /// Empty cells on the left are kept on a tape which is not two-way infinite, as the distance
+    /// to its left side matters.
+    fn configuration(&self) -> (Vec<Alphabet>, isize) {
+        let cells = self.cells.borrow();
+        let cursor = self.cursor.get();
+
+        let start = if self.two_way {
+            cells
+                .iter()
+                .position(|token| *token != self.empty)
+                .unwrap_or(cursor)
+        } else {
+            0
+        };
+        let end = cells
+            .iter()
+            .rposition(|token| *token != self.empty)
+            .map_or(start, |last| last + 1);
+
+        (
+            cells.range(start..end).cloned().collect(),
+            cursor as isize - start as isize,
+        )
+    }
 }
 
 impl<Alphabet: Clone + fmt::Display> TuringTape<Alphabet> {
-    /// Runs from start state until one of the end states has been reached.
+    /// Runs from start state until one of the end states has been reached, printing the tape
+    /// and state after every step using the [`PrintObserver`].
     /// Will return the end state.
-    pub fn debug_run_states<S: TuringStates<Alphabet> + PartialEq + fmt::Debug>(
+    ///
+    /// As the observer is handed a [`Step`] record of every step, the states have to implement
+    /// `Clone`, which they did not have to before observers were introduced.
+    pub fn debug_run_states<S: TuringStates<Alphabet> + Clone + fmt::Debug, E: EndStates<S>>(
         &self,
-        mut start_state: S,
-        end_states: Vec<S>,
+        start_state: S,
+        end_states: E,
     ) -> S {
-        while !end_states.contains(&start_state) {
-            start_state.internal_step(self);
-            println!("{}      :      {:?}", self, start_state);
+        self.run_states_observed(start_state, end_states, &mut PrintObserver)
+    }
+}
+
+/// Take steps from a state until one of the end states has been reached, `max_steps` steps have
+/// been taken or a step fails. Will return how the run ended.
+fn run_limited<S, E: EndStates<S>>(
+    mut state: S,
+    end_states: E,
+    max_steps: usize,
+    mut step: impl FnMut(&mut S) -> Result<(), Error>,
+) -> RunOutcome<S> {
+    let mut steps = 0;
+
+    while !end_states.is_halting(&state) {
+        if steps == max_steps {
+            return RunOutcome::OutOfSteps { state, steps };
         }
 
-        start_state
+        match step(&mut state) {
+            Ok(()) => steps += 1,
+            Err(error) => return RunOutcome::from_error(state, steps, error),
+        }
     }
+
+    RunOutcome::Halted { state, steps }
 }
 
-impl<Alphabet: Clone> From<TuringTape<Alphabet>> for Vec<Alphabet> {
+impl<Alphabet> From<TuringTape<Alphabet>> for Vec<Alphabet> {
     fn from(tape: TuringTape<Alphabet>) -> Vec<Alphabet> {
-        let cursor_initial = tape.cursor.borrow().clone();
-        let mut v = Vec::new();
+        tape.cells.into_inner().into()
+    }
+}
 
-        // Move all the way to the left
-        while tape.cursor.borrow().clone().prev().is_some() {
-            tape.step_left();
+/// The way a run with a limited amount of steps ended
+#[derive(Debug, Clone, PartialEq)]
+pub enum RunOutcome<S> {
+    /// One of the end states has been reached
+    Halted {
+        /// The end state which has been reached
+        state: S,
+        /// The amount of steps taken
+        steps: usize,
+    },
+    /// The maximum amount of steps has been taken before reaching an end state
+    OutOfSteps {
+        /// The state the machine was in after the last step
+        state: S,
+        /// The amount of steps taken
+        steps: usize,
+    },
+    /// The cursor would have gone off the left side of a tape which is not two-way infinite
+    FellOffLeft {
+        /// The state which attempted to move off the tape
+        state: S,
+        /// The amount of steps taken before attempting to move off the tape
+        steps: usize,
+    },
+    /// The machine halted as no transition is defined for the current state and token
+    UndefinedTransition {
+        /// The state without a transition for the token at the cursor
+        state: S,
+        /// The amount of steps taken
+        steps: usize,
+    },
+    /// The machine ended up in a configuration it has been in before, so it will never halt
+    Loops {
+        /// The state of the repeated configuration
+        state: S,
+        /// The amount of steps taken until the configuration repeated
+        steps: usize,
+        /// The amount of steps between the two occurrences of the configuration
+        cycle_length: usize,
+    },
+    /// The step function returned any other error
+    Failed {
+        /// The state whose step failed
+        state: S,
+        /// The amount of steps taken
+        steps: usize,
+        /// The error returned by the step function
+        error: Error,
+    },
+}
+
+impl<S> RunOutcome<S> {
+    /// Create the outcome of a run which stopped on an error of the step function after `steps`
+    /// steps
+    pub fn from_error(state: S, steps: usize, error: Error) -> RunOutcome<S> {
+        match error {
+            Error::FellOffLeft => RunOutcome::FellOffLeft { state, steps },
+            Error::UndefinedTransition => RunOutcome::UndefinedTransition { state, steps },
+            error => RunOutcome::Failed {
+                state,
+                steps,
+                error,
+            },
         }
+    }
 
-        // Move all the way back to the right adding the cursor
-        // to the vector along the way
-        while tape.cursor.borrow().clone().next().is_some() {
-            v.push(tape.get_cursor());
-            tape.step_right();
+    /// Fetch the state the machine ended in
+    pub fn state(&self) -> &S {
+        match self {
+            RunOutcome::Halted { state, .. }
+            | RunOutcome::OutOfSteps { state, .. }
+            | RunOutcome::FellOffLeft { state, .. }
+            | RunOutcome::UndefinedTransition { state, .. }
+            | RunOutcome::Loops { state, .. }
+            | RunOutcome::Failed { state, .. } => state,
         }
+    }
 
-        // Add the last element
-        v.push(tape.get_cursor());
+    /// Take the state the machine ended in
+    pub fn into_state(self) -> S {
+        match self {
+            RunOutcome::Halted { state, .. }
+            | RunOutcome::OutOfSteps { state, .. }
+            | RunOutcome::FellOffLeft { state, .. }
+            | RunOutcome::UndefinedTransition { state, .. }
+            | RunOutcome::Loops { state, .. }
+            | RunOutcome::Failed { state, .. } => state,
+        }
+    }
 
-        // Set the cursor back to it's previous position
-        let mut cursor = tape.cursor.borrow_mut();
-        *cursor = cursor_initial;
+    /// Fetch the amount of steps taken
+    pub fn steps(&self) -> usize {
+        match self {
+            RunOutcome::Halted { steps, .. }
+            | RunOutcome::OutOfSteps { steps, .. }
+            | RunOutcome::FellOffLeft { steps, .. }
+            | RunOutcome::UndefinedTransition { steps, .. }
+            | RunOutcome::Loops { steps, .. }
+            | RunOutcome::Failed { steps, .. } => *steps,
+        }
+    }
 
-        v
+    /// Returns whether one of the end states has been reached
+    pub fn is_halted(&self) -> bool {
+        matches!(self, RunOutcome::Halted { .. })
     }
 }
 
 /// Define the movement direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Move {
     /// Move left one cell
     Left,
@@ -233,6 +572,14 @@ pub trait TuringStates<Alphabet: Clone>: Sized + PartialEq {
     /// Output the new state, token at current cursor position, and move of the cursor position
     fn step(&self, current_token: Alphabet) -> (Self, Alphabet, Move);
 
+    /// The fallible internal step function
+    ///
+    /// Can be overwritten to return [`Error::UndefinedTransition`] for state and token
+    /// combinations without a transition. Defaults to always using [`TuringStates::step`].
+    fn try_step(&self, current_token: Alphabet) -> Result<(Self, Alphabet, Move), Error> {
+        Ok(self.step(current_token))
+    }
+
     /// Execute one step of the turing machine
     fn internal_step(&mut self, tape: &TuringTape<Alphabet>) {
         let (state, replace, mv) = self.step(tape.get_cursor());
@@ -244,22 +591,23 @@ pub trait TuringStates<Alphabet: Clone>: Sized + PartialEq {
         tape.set_cursor(replace);
 
         // Update cursor position
-        match mv {
-            Move::Left => {
-                tape.step_left();
-            }
-            Move::Stay => {}
-            Move::Right => {
-                tape.step_right();
-            }
-        };
+        tape.move_cursor(mv);
     }
 
-    /// Run this turing machine from a start state, until it eaches a final state.
+    /// Execute one step of the turing machine
+    ///
+    /// Will return an error instead of panicking if the step cannot be executed. The state and
+    /// the tape are then left untouched.
+    fn try_internal_step(&mut self, tape: &TuringTape<Alphabet>) -> Result<(), Error> {
+        tape.apply_step(self).map(|_| ())
+    }
+
+    /// Run this turing machine from a start state, until it eaches a final state or no
+    /// transition is defined.
     /// Will return a tuple containing the end_state and a vector of the memory state.
-    fn run_until_end(
+    fn run_until_end<E: EndStates<Self>>(
         start_state: Self,
-        end_states: Vec<Self>,
+        end_states: E,
         empty_token: Alphabet,
         start_token: Alphabet,
         initial_state: Vec<Alphabet>,
@@ -268,11 +616,53 @@ pub trait TuringStates<Alphabet: Clone>: Sized + PartialEq {
         let end_state = tape.run_states(start_state, end_states);
         (end_state, tape.into())
     }
+
+    /// Run this turing machine from a start state, until it reaches a final state or has taken
+    /// `max_steps` steps.
+    /// Will return a tuple containing how the run ended and a vector of the memory state.
+    fn run_until_end_limited<E: EndStates<Self>>(
+        start_state: Self,
+        end_states: E,
+        empty_token: Alphabet,
+        start_token: Alphabet,
+        initial_state: Vec<Alphabet>,
+        max_steps: usize,
+    ) -> (RunOutcome<Self>, Vec<Alphabet>) {
+        let tape = TuringTape::new(empty_token, start_token, initial_state);
+        let outcome = tape.run_states_limited(start_state, end_states, max_steps);
+        (outcome, tape.into())
+    }
+}
+
+/// A trait that implements the behaviour for turing states with a partial transition function
+///
+/// Every [`PartialTuringStates`] implementation is also a [`TuringStates`] implementation, where
+/// the machine halts once no transition is defined. [`TuringTape::run_states`] returns the state
+/// the machine halted in, [`TuringTape::run_states_limited`] reports this halt as
+/// [`RunOutcome::UndefinedTransition`] and [`TuringTape::try_run_states`] as
+/// [`Error::UndefinedTransition`].
+pub trait PartialTuringStates<Alphabet: Clone>: Sized + PartialEq {
+    /// The partial internal step function
+    /// Output the new state, token at current cursor position, and move of the cursor position,
+    /// or `None` if no transition is defined for the state and token.
+    fn partial_step(&self, current_token: Alphabet) -> Option<(Self, Alphabet, Move)>;
+}
+
+impl<Alphabet: Clone, S: PartialTuringStates<Alphabet>> TuringStates<Alphabet> for S {
+    fn step(&self, current_token: Alphabet) -> (Self, Alphabet, Move) {
+        self.partial_step(current_token)
+            .expect("No transition defined for the current state and token")
+    }
+
+    fn try_step(&self, current_token: Alphabet) -> Result<(Self, Alphabet, Move), Error> {
+        self.partial_step(current_token)
+            .ok_or(Error::UndefinedTransition)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    #[derive(PartialEq, Clone, Debug)]
+    #[derive(PartialEq, Eq, Hash, Clone, Debug)]
     pub enum Bit {
         Delta,
         Zero,
@@ -372,4 +762,454 @@ mod tests {
         assert_eq!(tape.step_right(), tape.get_cursor());
         assert_eq!(tape.step_right(), tape.get_cursor());
     }
+
+    #[test]
+    #[should_panic]
+    fn one_way_step_off_left() {
+        use Bit::*;
+        let tape = TuringTape::new(Delta, Zero, vec![One]);
+        tape.step_left();
+    }
+
+    #[test]
+    fn two_way_stepping() {
+        use Bit::*;
+        let tape = TuringTape::new_two_way(Delta, Zero, vec![One]);
+
+        assert_eq!(tape.step_left(), Delta);
+        tape.set_cursor(One);
+        assert_eq!(tape.step_left(), Delta);
+        assert_eq!(tape.step_right(), One);
+        assert_eq!(tape.step_right(), Zero);
+        assert_eq!(tape.step_right(), One);
+
+        assert_eq!(format!("{}", tape), "|  _  |  1  |  0  |> 1 <|");
+        assert_eq!(<Vec<Bit>>::from(tape), vec![Delta, One, Zero, One]);
+    }
+
+    #[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+    enum Walker {
+        Right,
+        Left,
+        Done,
+    }
+
+    impl TuringStates<Bit> for Walker {
+        fn step(&self, current_token: Bit) -> (Self, Bit, Move) {
+            match (self, current_token) {
+                (Walker::Right, Bit::Delta) => (Walker::Left, Bit::One, Move::Left),
+                (Walker::Right, token) => (Walker::Right, token, Move::Right),
+                (Walker::Left, Bit::Zero) => (Walker::Done, Bit::Zero, Move::Stay),
+                (_, token) => (*self, token, Move::Left),
+            }
+        }
+    }
+
+    #[test]
+    fn run_limited() {
+        use Bit::*;
+
+        let tape = TuringTape::new(Delta, Zero, vec![One, One]);
+        assert_eq!(
+            tape.run_states_limited(Walker::Right, vec![Walker::Done], 100),
+            RunOutcome::Halted {
+                state: Walker::Done,
+                steps: 7
+            }
+        );
+
+        let tape = TuringTape::new(Delta, One, vec![One, One]);
+        assert_eq!(
+            tape.run_states_limited(Walker::Right, vec![Walker::Done], 100),
+            RunOutcome::FellOffLeft {
+                state: Walker::Left,
+                steps: 6
+            }
+        );
+
+        let tape = TuringTape::new(Delta, Zero, vec![One, One]);
+        assert_eq!(
+            tape.run_states_limited(Walker::Right, vec![], 3),
+            RunOutcome::OutOfSteps {
+                state: Walker::Right,
+                steps: 3
+            }
+        );
+
+        assert_eq!(
+            Walker::run_until_end_limited(
+                Walker::Right,
+                vec![Walker::Done],
+                Delta,
+                Zero,
+                vec![],
+                2
+            ),
+            (
+                RunOutcome::OutOfSteps {
+                    state: Walker::Left,
+                    steps: 2
+                },
+                vec![Zero, One]
+            )
+        );
+    }
+
+    #[test]
+    fn try_operations() {
+        use Bit::*;
+
+        let tape = TuringTape::new(Delta, Zero, vec![One]);
+        assert_eq!(tape.try_step_left(), Err(Error::FellOffLeft));
+        assert_eq!(tape.step_right(), One);
+        assert_eq!(tape.try_step_left(), Ok(Zero));
+
+        let mut state = Walker::Left;
+        assert_eq!(state.try_internal_step(&tape), Ok(()));
+        assert_eq!(state, Walker::Done);
+
+        let tape = TuringTape::new(Delta, One, vec![]);
+        let mut state = Walker::Left;
+        assert_eq!(state.try_internal_step(&tape), Err(Error::FellOffLeft));
+        assert_eq!(state, Walker::Left);
+        assert_eq!(tape.get_cursor(), One);
+
+        let tape = TuringTape::new(Delta, Zero, vec![One]);
+        assert_eq!(
+            tape.try_run_states(Walker::Right, vec![Walker::Done]),
+            Ok(Walker::Done)
+        );
+        let tape = TuringTape::new(Delta, One, vec![One]);
+        assert_eq!(
+            tape.try_run_states(Walker::Right, vec![Walker::Done]),
+            Err(Error::FellOffLeft)
+        );
+
+        assert_eq!(
+            RunOutcome::from_error(Walker::Left, 2, Error::FellOffLeft),
+            RunOutcome::FellOffLeft {
+                state: Walker::Left,
+                steps: 2
+            }
+        );
+        assert_eq!(
+            RunOutcome::from_error(Walker::Left, 2, Error::Format("bad".to_string())),
+            RunOutcome::Failed {
+                state: Walker::Left,
+                steps: 2,
+                error: Error::Format("bad".to_string())
+            }
+        );
+    }
+
+    /// Bounces between two `Delta` cells without ever changing the tape
+    #[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+    enum Bouncer {
+        Right,
+        Left,
+    }
+
+    impl TuringStates<Bit> for Bouncer {
+        fn step(&self, current_token: Bit) -> (Self, Bit, Move) {
+            match (self, current_token) {
+                (Bouncer::Right, Bit::Delta) => (Bouncer::Left, Bit::Delta, Move::Left),
+                (Bouncer::Right, token) => (Bouncer::Right, token, Move::Right),
+                (Bouncer::Left, Bit::Delta) => (Bouncer::Right, Bit::Delta, Move::Right),
+                (Bouncer::Left, token) => (Bouncer::Left, token, Move::Left),
+            }
+        }
+    }
+
+    #[test]
+    fn detect_loops() {
+        use Bit::*;
+
+        let tape = TuringTape::new(Delta, Delta, vec![Zero, One, Delta]);
+        tape.step_right();
+        assert_eq!(
+            tape.run_states_detect_loops(Bouncer::Right, vec![], 100),
+            RunOutcome::Loops {
+                state: Bouncer::Right,
+                steps: 6,
+                cycle_length: 6
+            }
+        );
+
+        let tape = TuringTape::new(Delta, Zero, vec![One, One]);
+        assert_eq!(
+            tape.run_states_detect_loops(Walker::Right, vec![Walker::Done], 100),
+            RunOutcome::Halted {
+                state: Walker::Done,
+                steps: 7
+            }
+        );
+
+        // Moving onto a new empty cell does not make the configuration differ
+        let tape = TuringTape::new(Delta, Delta, vec![]);
+        assert_eq!(
+            tape.run_states_detect_loops(Bouncer::Left, vec![], 100),
+            RunOutcome::Loops {
+                state: Bouncer::Left,
+                steps: 2,
+                cycle_length: 2
+            }
+        );
+    }
+
+    #[test]
+    fn execution_steps() {
+        use Bit::*;
+
+        let tape = TuringTape::new_two_way(Delta, Zero, vec![One]);
+        let steps: Vec<_> = tape.execute(Walker::Right, vec![Walker::Done]).collect();
+
+        assert_eq!(steps.len(), 5);
+        assert_eq!(
+            steps[2],
+            Step {
+                index: 2,
+                from: Walker::Right,
+                read: Delta,
+                written: One,
+                mv: Move::Left,
+                to: Walker::Left,
+                position: 1,
+            }
+        );
+        assert_eq!(steps[4].to, Walker::Done);
+        assert_eq!(steps[4].position, 0);
+
+        let tape = TuringTape::new(Delta, One, vec![]);
+        let mut execution = tape.execute(Walker::Right, vec![Walker::Done]);
+        let moves: Vec<_> = execution.by_ref().map(|step| step.mv).collect();
+        assert_eq!(moves, vec![Move::Right, Move::Left]);
+        assert_eq!(execution.error(), Some(&Error::FellOffLeft));
+        assert_eq!(execution.state(), &Walker::Left);
+    }
+
+    #[test]
+    fn observers() {
+        use Bit::*;
+
+        #[derive(Default)]
+        struct Recorder {
+            before: Vec<Walker>,
+            halted: Option<(Walker, usize)>,
+        }
+
+        impl Observer<Bit, Walker> for Recorder {
+            fn before_step(&mut self, _tape: &TuringTape<Bit>, state: &Walker) {
+                self.before.push(*state);
+            }
+
+            fn on_halt(&mut self, _tape: &TuringTape<Bit>, state: &Walker, steps: usize) {
+                self.halted = Some((*state, steps));
+            }
+        }
+
+        let tape = TuringTape::new(Delta, Zero, vec![One]);
+        let mut recorder = Recorder::default();
+        tape.run_states_observed(Walker::Right, vec![Walker::Done], &mut recorder);
+        assert_eq!(
+            recorder.before,
+            vec![
+                Walker::Right,
+                Walker::Right,
+                Walker::Right,
+                Walker::Left,
+                Walker::Left
+            ]
+        );
+        assert_eq!(recorder.halted, Some((Walker::Done, 5)));
+
+        let tape = TuringTape::new(Delta, Zero, vec![One]);
+        let mut counter = StepCounter::default();
+        tape.run_states_observed(Walker::Right, vec![Walker::Done], &mut counter);
+        assert_eq!(counter.steps, 5);
+
+        let tape = TuringTape::new(Delta, Zero, vec![One]);
+        let mut steps = Vec::new();
+        tape.run_states_observed(Walker::Right, vec![Walker::Done], &mut steps);
+        assert_eq!(
+            steps
+                .iter()
+                .map(|step| step.written.clone())
+                .collect::<Vec<_>>(),
+            vec![Zero, One, One, One, Zero]
+        );
+    }
+
+    #[test]
+    fn run_stats() {
+        use Bit::*;
+
+        let tape = TuringTape::new_two_way(Delta, Zero, vec![One]);
+        tape.step_right();
+        let (end_state, stats) = tape.run_states_with_stats(Walker::Right, vec![Walker::Done]);
+
+        assert_eq!(end_state, Walker::Done);
+        assert_eq!(stats.steps(), 4);
+        assert_eq!(stats.cells_visited(), 3);
+        assert_eq!(stats.leftmost(), 0);
+        assert_eq!(stats.rightmost(), 2);
+        assert_eq!(stats.state_visits(&Walker::Right), 2);
+        assert_eq!(stats.state_visits(&Walker::Left), 2);
+        assert_eq!(stats.state_visits(&Walker::Done), 1);
+        assert_eq!(stats.transition_usage(&Walker::Right, &One), 1);
+        assert_eq!(stats.transition_usage(&Walker::Right, &Zero), 0);
+        assert_eq!(stats.changed_writes(), 1);
+    }
+
+    #[test]
+    fn tape_strings() {
+        use Bit::*;
+
+        let parse = |c| match c {
+            '_' => Some(Delta),
+            '0' => Some(Zero),
+            '1' => Some(One),
+            _ => None,
+        };
+
+        let tape = TuringTape::from_str_with("_01[1]0", Delta, parse).unwrap();
+        assert_eq!(tape.get_cursor(), One);
+        tape.step_right();
+        tape.step_right();
+        assert_eq!(tape.to_compact_string(), "_0110[_]");
+        assert_eq!(
+            <Vec<Bit>>::from(tape),
+            vec![Delta, Zero, One, One, Zero, Delta]
+        );
+
+        let tape = TuringTape::from_str_with("_01", Delta, parse).unwrap();
+        assert_eq!(tape.to_compact_string(), "[_]01");
+        assert!(!tape.is_two_way());
+
+        let tape = TuringTape::from_str_with_two_way("_[0]1", Delta, parse).unwrap();
+        assert!(tape.is_two_way());
+        tape.step_left();
+        tape.step_left();
+        assert_eq!(tape.to_compact_string(), "[_]_01");
+        assert_eq!(tape.position(), -1);
+
+        for invalid in &["", "_2", "[_", "_]", "[]_", "[_0]", "[_][0]"] {
+            assert!(TuringTape::from_str_with(invalid, Delta, parse).is_err());
+        }
+    }
+
+    #[test]
+    fn cursor_offset() {
+        use Bit::*;
+
+        let tape = TuringTape::new(Delta, Zero, vec![One, Zero]).with_cursor(2);
+        assert_eq!(tape.get_cursor(), Zero);
+        assert_eq!(tape.position(), 2);
+        tape.step_left();
+        assert_eq!(tape.get_cursor(), One);
+        assert_eq!(tape.position(), 1);
+
+        let tape = TuringTape::new_two_way(Delta, Zero, vec![One]).with_cursor(3);
+        assert_eq!(tape.get_cursor(), Delta);
+        assert_eq!(tape.position(), 3);
+        for _ in 0..4 {
+            tape.step_left();
+        }
+        assert_eq!(tape.position(), -1);
+        assert_eq!(<Vec<Bit>>::from(tape), vec![Delta, Zero, One, Delta, Delta]);
+    }
+
+    #[test]
+    fn stock_encodings() {
+        use Bit::*;
+
+        let unary = Unary::new(One, Delta);
+        assert_eq!(
+            unary.encode(vec![2, 0, 1]),
+            vec![One, One, Delta, Delta, One]
+        );
+        let tape = TuringTape::new(Delta, Delta, unary.encode((3, 1))).with_cursor(1);
+        assert_eq!(unary.decode(&tape), Ok(3));
+        assert_eq!(unary.decode(&TuringTape::new(Delta, Delta, vec![])), Ok(0));
+
+        let binary = Binary::new(Zero, One, Delta);
+        assert_eq!(binary.encode((5, 0)), vec![One, Zero, One, Delta, Zero]);
+        let tape = TuringTape::new(Delta, Delta, binary.encode(6)).with_cursor(1);
+        assert_eq!(binary.decode(&tape), Ok(6));
+        assert!(binary
+            .decode(&TuringTape::new(Delta, Delta, vec![One]))
+            .is_err());
+    }
+
+    #[test]
+    fn snapshot_restore() {
+        use Bit::*;
+
+        let tape = TuringTape::new_two_way(Delta, Zero, vec![One]);
+        tape.step_left();
+        tape.step_right();
+        let snapshot = tape.snapshot();
+        assert_eq!(snapshot.position(), 0);
+
+        let branch = tape.clone();
+        assert_eq!(
+            branch.run_states(Walker::Right, vec![Walker::Done]),
+            Walker::Done
+        );
+        tape.set_cursor(One);
+        tape.step_left();
+        assert_eq!(tape.position(), -1);
+        assert_eq!(tape.snapshot().cells(), &[Delta, One, One]);
+        assert_eq!(branch.snapshot().cells(), &[Delta, Zero, One, One]);
+
+        tape.restore(&snapshot);
+        assert_eq!(tape.snapshot(), snapshot);
+        assert_eq!(tape.get_cursor(), Zero);
+        branch.restore(&snapshot);
+        assert_eq!(branch.snapshot(), snapshot);
+        assert_eq!(<Vec<Bit>>::from(branch), vec![Delta, Zero, One]);
+    }
+
+    #[test]
+    fn recording() {
+        use Bit::*;
+
+        let tape = TuringTape::new_two_way(Delta, Zero, vec![One]);
+        tape.step_right();
+        let mut recording = tape.record(Walker::Right, vec![Walker::Done]);
+
+        assert_eq!(recording.run(), Ok(()));
+        assert_eq!(recording.steps(), 4);
+        assert_eq!(recording.state(), &Walker::Done);
+        assert_eq!(recording.step_forward(), Ok(false));
+        assert_eq!(tape.to_compact_string(), "[0]11");
+
+        assert!(recording.step_back());
+        assert_eq!(recording.state(), &Walker::Left);
+        assert_eq!(tape.to_compact_string(), "[0]11");
+
+        assert_eq!(recording.goto(2), Ok(true));
+        assert_eq!(recording.state(), &Walker::Left);
+        assert_eq!(tape.to_compact_string(), "0[1]1");
+
+        assert_eq!(recording.goto(0), Ok(true));
+        assert!(!recording.step_back());
+        assert_eq!(recording.state(), &Walker::Right);
+        assert_eq!(tape.to_compact_string(), "0[1]");
+
+        assert_eq!(recording.goto(10), Ok(false));
+        assert_eq!(recording.steps(), 4);
+        assert_eq!(recording.recorded(), 4);
+        assert_eq!(tape.to_compact_string(), "[0]11");
+
+        // Cells added on the left are removed again as well
+        let tape = TuringTape::new_two_way(Delta, One, vec![]);
+        let mut recording = tape.record(Walker::Left, vec![Walker::Done]);
+        assert_eq!(recording.goto(2), Ok(true));
+        assert_eq!(tape.to_compact_string(), "[_]_1");
+        assert_eq!(tape.position(), -2);
+
+        assert_eq!(recording.goto(0), Ok(true));
+        assert_eq!(tape.to_compact_string(), "[1]");
+        assert_eq!(tape.position(), 0);
+    }
 }
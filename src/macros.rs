@@ -0,0 +1,121 @@
+/// Define a turing machine by its transition table
+///
+/// Generates the state enum, a [`PartialTuringStates`](crate::PartialTuringStates)
+/// implementation and an `end_states` function returning the listed end states. Every
+/// transition is written as `State, read => NewState, write, move;` where the tokens are
+/// variants of the alphabet and the move is `L`, `S` or `R`. Reading `_` matches any token and
+/// writing `_` keeps the token which was read. The first matching transition is taken and the
+/// machine halts if none matches.
+///
+/// ```
+/// use turingmachine_rs::{turing_machine, TuringTape};
+///
+/// #[derive(Clone, PartialEq, Debug)]
+/// enum Alphabet {
+///     Delta,
+///     Zero,
+///     One,
+/// }
+///
+/// turing_machine! {
+///     /// Replaces all the Ones in the input with zeros
+///     pub enum States: Alphabet {
+///         Start,
+///         Started,
+///         ValidEnd,
+///     }
+///
+///     end_states = [ValidEnd];
+///
+///     Start, _ => Started, _, R;
+///     Started, One => Started, Zero, R;
+///     Started, Delta => ValidEnd, Delta, S;
+///     Started, _ => Started, _, R;
+/// }
+///
+/// let tape = TuringTape::new(Alphabet::Delta, Alphabet::Delta, vec![Alphabet::One]);
+/// assert_eq!(tape.run_states(States::Start, States::end_states()), States::ValidEnd);
+/// assert_eq!(
+///     <Vec<Alphabet>>::from(tape),
+///     vec![Alphabet::Delta, Alphabet::Zero, Alphabet::Delta]
+/// );
+/// ```
+///
+/// Tokens which are not variants of the alphabet do not compile:
+///
+/// ```compile_fail
+/// use turingmachine_rs::turing_machine;
+///
+/// #[derive(Clone, PartialEq, Debug)]
+/// enum Alphabet {
+///     Delta,
+///     One,
+/// }
+///
+/// turing_machine! {
+///     enum States: Alphabet {
+///         Start,
+///         End,
+///     }
+///
+///     end_states = [End];
+///
+///     Start, Onee => End, _, S;
+/// }
+/// ```
+#[macro_export]
+macro_rules! turing_machine {
+    (
+        $(#[$attr:meta])*
+        $vis:vis enum $name:ident : $alphabet:ident {
+            $($state:ident),* $(,)?
+        }
+
+        end_states = [$($end:ident),* $(,)?];
+
+        $($from:ident, $read:tt => $to:ident, $write:tt, $mv:ident;)*
+    ) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        $vis enum $name {
+            $($state),*
+        }
+
+        impl $name {
+            /// The states in which the machine halts
+            #[allow(dead_code)]
+            $vis fn end_states() -> Vec<$name> {
+                vec![$($name::$end),*]
+            }
+        }
+
+        impl $crate::PartialTuringStates<$alphabet> for $name {
+            fn partial_step(
+                &self,
+                current_token: $alphabet,
+            ) -> Option<($name, $alphabet, $crate::Move)> {
+                match (*self, &current_token) {
+                    $(
+                        ($name::$from, $crate::turing_machine!(@read $alphabet, $read)) => Some((
+                            $name::$to,
+                            $crate::turing_machine!(@write $alphabet, current_token, $write),
+                            $crate::turing_machine!(@move $mv),
+                        )),
+                    )*
+                    #[allow(unreachable_patterns)]
+                    _ => None,
+                }
+            }
+        }
+    };
+
+    (@read $alphabet:ident, _) => { _ };
+    (@read $alphabet:ident, $read:ident) => { $alphabet::$read };
+
+    (@write $alphabet:ident, $token:ident, _) => { $token };
+    (@write $alphabet:ident, $token:ident, $write:ident) => { $alphabet::$write };
+
+    (@move L) => { $crate::Move::Left };
+    (@move S) => { $crate::Move::Stay };
+    (@move R) => { $crate::Move::Right };
+}
@@ -0,0 +1,215 @@
+use std::fmt;
+use std::hash::Hash;
+
+use crate::{
+    run_limited, BoundState, EndStates, Error, Move, MultiTapeObserver, PrintObserver, RunOutcome,
+    Table, TuringTape,
+};
+
+/// The new state, tokens at the current cursor positions and moves of the cursor positions
+type Transition<State, Alphabet, const K: usize> = (State, [Alphabet; K], [Move; K]);
+
+/// A turing machine operating on `K` tapes, each with their own cursor
+pub struct MultiTapeMachine<Alphabet, const K: usize> {
+    /// The tapes of the machine
+    tapes: [TuringTape<Alphabet>; K],
+}
+
+impl<Alphabet: fmt::Display, const K: usize> fmt::Display for MultiTapeMachine<Alphabet, K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, tape) in self.tapes.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+
+            write!(f, "{}", tape)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<Alphabet: Clone, const K: usize> MultiTapeMachine<Alphabet, K> {
+    /// Initialize a new MultiTapeMachine operating on the given tapes
+    pub fn new(tapes: [TuringTape<Alphabet>; K]) -> MultiTapeMachine<Alphabet, K> {
+        MultiTapeMachine { tapes }
+    }
+
+    /// Fetch one of the tapes
+    ///
+    /// Will panic if the index is not smaller than `K`.
+    pub fn tape(&self, index: usize) -> &TuringTape<Alphabet> {
+        &self.tapes[index]
+    }
+
+    /// Take the tapes of the machine
+    pub fn into_tapes(self) -> [TuringTape<Alphabet>; K] {
+        self.tapes
+    }
+
+    /// Fetch the tokens at the cursors of all tapes
+    pub fn get_cursors(&self) -> [Alphabet; K] {
+        std::array::from_fn(|index| self.tapes[index].get_cursor())
+    }
+
+    /// Runs from start state until one of the end states has been reached or no transition is
+    /// defined for the current state and tokens.
+    /// Will return the state the machine halted in.
+    ///
+    /// Will panic if a cursor goes off the left side of a tape which is not two-way infinite.
+    pub fn run_states<S: MultiTapeStates<Alphabet, K>, E: EndStates<S>>(
+        &self,
+        start_state: S,
+        end_states: E,
+    ) -> S {
+        self.run_states_observed(start_state, end_states, &mut ())
+    }
+
+    /// Runs like [`MultiTapeMachine::run_states`], notifying the observer along the way.
+    /// Will return the state the machine halted in.
+    pub fn run_states_observed<
+        S: MultiTapeStates<Alphabet, K>,
+        E: EndStates<S>,
+        O: MultiTapeObserver<Alphabet, S, K>,
+    >(
+        &self,
+        mut start_state: S,
+        end_states: E,
+        observer: &mut O,
+    ) -> S {
+        let mut steps = 0;
+
+        while !end_states.is_halting(&start_state) {
+            observer.before_step(self, &start_state);
+
+            match start_state.try_internal_step(self) {
+                Ok(()) => observer.after_step(self, &start_state),
+                // A partial transition function halts the machine where it is not defined
+                Err(Error::UndefinedTransition) => break,
+                Err(error) => panic!("{}", error),
+            }
+
+            steps += 1;
+        }
+
+        observer.on_halt(self, &start_state, steps);
+        start_state
+    }
+
+    /// Runs from start state until one of the end states has been reached or `max_steps` steps
+    /// have been taken. Will return how the run ended.
+    ///
+    /// Contrary to [`MultiTapeMachine::run_states`], this will not panic when a cursor goes off
+    /// the left side of its tape. The transition leading off the tape is then not executed.
+    pub fn run_states_limited<S: MultiTapeStates<Alphabet, K>, E: EndStates<S>>(
+        &self,
+        state: S,
+        end_states: E,
+        max_steps: usize,
+    ) -> RunOutcome<S> {
+        run_limited(state, end_states, max_steps, |state| {
+            state.try_internal_step(self)
+        })
+    }
+
+    /// Write the tokens at the cursors and move the cursors of all tapes
+    fn apply(&self, replace: [Alphabet; K], mvs: [Move; K]) {
+        for ((tape, token), mv) in self.tapes.iter().zip(replace).zip(mvs) {
+            tape.set_cursor(token);
+            tape.move_cursor(mv);
+        }
+    }
+}
+
+impl<Alphabet: Clone + fmt::Display, const K: usize> MultiTapeMachine<Alphabet, K> {
+    /// Runs from start state until one of the end states has been reached, printing the tapes
+    /// and state after every step using the [`PrintObserver`].
+    /// Will return the end state.
+    pub fn debug_run_states<S: MultiTapeStates<Alphabet, K> + fmt::Debug, E: EndStates<S>>(
+        &self,
+        start_state: S,
+        end_states: E,
+    ) -> S {
+        self.run_states_observed(start_state, end_states, &mut PrintObserver)
+    }
+}
+
+/// A trait that implements the behaviour for the states of a machine operating on `K` tapes
+pub trait MultiTapeStates<Alphabet: Clone, const K: usize>: Sized + PartialEq {
+    /// The internal step function
+    /// Output the new state, tokens at the current cursor positions, and moves of the cursor
+    /// positions, one for each tape
+    fn step(&self, current_tokens: [Alphabet; K]) -> (Self, [Alphabet; K], [Move; K]);
+
+    /// The fallible internal step function
+    ///
+    /// Can be overwritten to return [`Error::UndefinedTransition`] for state and token
+    /// combinations without a transition. Defaults to always using [`MultiTapeStates::step`].
+    fn try_step(
+        &self,
+        current_tokens: [Alphabet; K],
+    ) -> Result<Transition<Self, Alphabet, K>, Error> {
+        Ok(self.step(current_tokens))
+    }
+
+    /// Execute one step of the turing machine
+    fn internal_step(&mut self, machine: &MultiTapeMachine<Alphabet, K>) {
+        let (state, replace, mvs) = self.step(machine.get_cursors());
+
+        // Update the current state
+        *self = state;
+
+        // Update cursor tokens and positions
+        machine.apply(replace, mvs);
+    }
+
+    /// Execute one step of the turing machine
+    ///
+    /// Will return an error instead of panicking if the step cannot be executed. The state and
+    /// the tapes are then left untouched.
+    fn try_internal_step(&mut self, machine: &MultiTapeMachine<Alphabet, K>) -> Result<(), Error> {
+        let (state, replace, mvs) = self.try_step(machine.get_cursors())?;
+
+        let off_tape = machine
+            .tapes
+            .iter()
+            .zip(mvs.iter())
+            .any(|(tape, mv)| matches!(mv, Move::Left) && !tape.can_step_left());
+        if off_tape {
+            return Err(Error::FellOffLeft);
+        }
+
+        *self = state;
+        machine.apply(replace, mvs);
+
+        Ok(())
+    }
+}
+
+/// A data-driven turing machine operating on `K` tapes, mapping each state and the tokens at
+/// the cursors to a transition
+///
+/// This is the multi-tape counterpart of the [`TransitionTable`](crate::TransitionTable).
+pub type MultiTapeTable<State, Alphabet, const K: usize> = Table<State, [Alphabet; K], [Move; K]>;
+
+/// A state of a [`MultiTapeTable`], which implements [`MultiTapeStates`] by looking up its
+/// transitions in the table
+pub type MultiTapeTableState<'a, State, Alphabet, const K: usize> =
+    BoundState<'a, State, [Alphabet; K], [Move; K]>;
+
+impl<'a, State: Clone + Eq + Hash, Alphabet: Clone + Eq + Hash, const K: usize>
+    MultiTapeStates<Alphabet, K> for MultiTapeTableState<'a, State, Alphabet, K>
+{
+    fn step(&self, current_tokens: [Alphabet; K]) -> (Self, [Alphabet; K], [Move; K]) {
+        self.try_step(current_tokens)
+            .expect("No transition defined for the current state and tokens")
+    }
+
+    fn try_step(
+        &self,
+        current_tokens: [Alphabet; K],
+    ) -> Result<Transition<Self, Alphabet, K>, Error> {
+        self.lookup(&current_tokens)
+            .ok_or(Error::UndefinedTransition)
+    }
+}
@@ -0,0 +1,108 @@
+use crate::{EndStates, Move, TuringTape};
+
+/// A trait that implements the behaviour for the states of a nondeterministic turing machine
+pub trait NondeterministicStates<Alphabet: Clone>: Sized + PartialEq + Clone {
+    /// The internal step function
+    /// Output all possible combinations of the new state, token at current cursor position, and
+    /// move of the cursor position. An empty vector halts this branch of the computation.
+    fn step(&self, current_token: Alphabet) -> Vec<(Self, Alphabet, Move)>;
+}
+
+/// A computation of a nondeterministic turing machine which reached an accepting state
+#[derive(Clone)]
+pub struct Computation<Alphabet, S> {
+    /// The states visited, from the start state up to and including the accepting state
+    pub states: Vec<S>,
+    /// The tape at the end of the computation
+    pub tape: TuringTape<Alphabet>,
+}
+
+/// The way a nondeterministic run ended
+#[derive(Clone)]
+pub enum SearchOutcome<Alphabet, S> {
+    /// A branch reached one of the accepting states
+    Accepted(Computation<Alphabet, S>),
+    /// All branches halted without reaching one of the accepting states
+    Rejected,
+    /// Branches were still running after the maximum depth
+    DepthExhausted,
+}
+
+impl<Alphabet, S> SearchOutcome<Alphabet, S> {
+    /// Returns whether a branch reached one of the accepting states
+    pub fn is_accepted(&self) -> bool {
+        matches!(self, SearchOutcome::Accepted(_))
+    }
+}
+
+impl<Alphabet: Clone> TuringTape<Alphabet> {
+    /// Explores all computations from the start state breadth-first, until a branch reaches one
+    /// of the accepting states or `max_depth` steps have been taken in every branch.
+    /// Will return how the search ended, together with the accepting computation.
+    ///
+    /// Branches going off the left side of a tape which is not two-way infinite are halted. The
+    /// tape itself is left untouched, every branch works on its own copy.
+    pub fn run_nondeterministic<S: NondeterministicStates<Alphabet>, E: EndStates<S>>(
+        &self,
+        start_state: S,
+        accept_states: E,
+        max_depth: usize,
+    ) -> SearchOutcome<Alphabet, S> {
+        // All visited states with the index of their parent, to reconstruct the computation
+        let mut visited: Vec<(S, Option<usize>)> = vec![(start_state, None)];
+        let mut frontier: Vec<(usize, TuringTape<Alphabet>)> = vec![(0, self.clone())];
+
+        for depth in 0..=max_depth {
+            if let Some(position) = frontier
+                .iter()
+                .position(|(index, _)| accept_states.is_halting(&visited[*index].0))
+            {
+                let (index, tape) = frontier.swap_remove(position);
+                return SearchOutcome::Accepted(Computation {
+                    states: path(&visited, index),
+                    tape,
+                });
+            }
+
+            if frontier.is_empty() {
+                return SearchOutcome::Rejected;
+            }
+
+            if depth == max_depth {
+                break;
+            }
+
+            let mut next_frontier = Vec::new();
+            for (index, tape) in frontier {
+                for (state, replace, mv) in visited[index].0.step(tape.get_cursor()) {
+                    if matches!(mv, Move::Left) && !tape.can_step_left() {
+                        continue;
+                    }
+
+                    let branch = tape.clone();
+                    branch.set_cursor(replace);
+                    branch.move_cursor(mv);
+
+                    visited.push((state, Some(index)));
+                    next_frontier.push((visited.len() - 1, branch));
+                }
+            }
+            frontier = next_frontier;
+        }
+
+        SearchOutcome::DepthExhausted
+    }
+}
+
+/// Collect the states from the root up to and including the state at `index`
+fn path<S: Clone>(visited: &[(S, Option<usize>)], mut index: usize) -> Vec<S> {
+    let mut states = vec![visited[index].0.clone()];
+
+    while let Some(parent) = visited[index].1 {
+        states.push(visited[parent].0.clone());
+        index = parent;
+    }
+
+    states.reverse();
+    states
+}
@@ -0,0 +1,127 @@
+use std::fmt;
+
+use crate::{EndStates, Error, MultiTapeMachine, Step, TuringStates, TuringTape};
+
+/// A trait for hooking into the execution of a turing machine
+///
+/// All callbacks do nothing by default, so only the interesting ones have to be implemented.
+pub trait Observer<Alphabet, S> {
+    /// Called before every step, with the state the step starts from
+    fn before_step(&mut self, _tape: &TuringTape<Alphabet>, _state: &S) {}
+
+    /// Called after every step, with the record of the step
+    fn after_step(&mut self, _tape: &TuringTape<Alphabet>, _step: &Step<Alphabet, S>) {}
+
+    /// Called once the machine halted, with the state it halted in and the amount of steps
+    /// taken
+    ///
+    /// The machine halts when one of the end states has been reached or no transition is
+    /// defined for the current state and token, so the state is not necessarily an end state.
+    fn on_halt(&mut self, _tape: &TuringTape<Alphabet>, _state: &S, _steps: usize) {}
+}
+
+/// A trait for hooking into the execution of a turing machine operating on `K` tapes
+///
+/// This is the multi-tape counterpart of the [`Observer`], where the callbacks get the machine
+/// instead of a single tape. All callbacks do nothing by default.
+pub trait MultiTapeObserver<Alphabet, S, const K: usize> {
+    /// Called before every step, with the state the step starts from
+    fn before_step(&mut self, _machine: &MultiTapeMachine<Alphabet, K>, _state: &S) {}
+
+    /// Called after every step, with the state the step ended in
+    fn after_step(&mut self, _machine: &MultiTapeMachine<Alphabet, K>, _state: &S) {}
+
+    /// Called once the machine halted, with the state it halted in and the amount of steps
+    /// taken
+    ///
+    /// The machine halts when one of the end states has been reached or no transition is
+    /// defined for the current state and tokens, so the state is not necessarily an end state.
+    fn on_halt(&mut self, _machine: &MultiTapeMachine<Alphabet, K>, _state: &S, _steps: usize) {}
+}
+
+/// Ignores all callbacks
+impl<Alphabet, S, const K: usize> MultiTapeObserver<Alphabet, S, K> for () {}
+
+/// An observer printing the tape and state to stdout after every step
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PrintObserver;
+
+impl<Alphabet: fmt::Display, S: fmt::Debug> Observer<Alphabet, S> for PrintObserver {
+    fn after_step(&mut self, tape: &TuringTape<Alphabet>, step: &Step<Alphabet, S>) {
+        println!("{}      :      {:?}", tape, step.to);
+    }
+}
+
+impl<Alphabet: fmt::Display, S: fmt::Debug, const K: usize> MultiTapeObserver<Alphabet, S, K>
+    for PrintObserver
+{
+    fn after_step(&mut self, machine: &MultiTapeMachine<Alphabet, K>, state: &S) {
+        println!("{}\n      :      {:?}", machine, state);
+    }
+}
+
+/// An observer counting the amount of steps taken
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct StepCounter {
+    /// The amount of steps taken
+    pub steps: usize,
+}
+
+impl<Alphabet, S> Observer<Alphabet, S> for StepCounter {
+    fn after_step(&mut self, _tape: &TuringTape<Alphabet>, _step: &Step<Alphabet, S>) {
+        self.steps += 1;
+    }
+}
+
+impl<Alphabet, S, const K: usize> MultiTapeObserver<Alphabet, S, K> for StepCounter {
+    fn after_step(&mut self, _machine: &MultiTapeMachine<Alphabet, K>, _state: &S) {
+        self.steps += 1;
+    }
+}
+
+/// Collects the records of all steps taken
+impl<Alphabet: Clone, S: Clone> Observer<Alphabet, S> for Vec<Step<Alphabet, S>> {
+    fn after_step(&mut self, _tape: &TuringTape<Alphabet>, step: &Step<Alphabet, S>) {
+        self.push(step.clone());
+    }
+}
+
+impl<Alphabet: Clone> TuringTape<Alphabet> {
+    /// Runs from start state until one of the end states has been reached, notifying the
+    /// observer along the way.
+    /// Will return the end state.
+    ///
+    /// Like [`TuringTape::run_states`], the machine halts once no transition is defined and
+    /// this will panic if the cursor goes off the left side of the tape.
+    pub fn run_states_observed<
+        S: TuringStates<Alphabet> + Clone,
+        E: EndStates<S>,
+        O: Observer<Alphabet, S>,
+    >(
+        &self,
+        start_state: S,
+        end_states: E,
+        observer: &mut O,
+    ) -> S {
+        let mut execution = self.execute(start_state, end_states);
+
+        while !execution.is_halted() {
+            observer.before_step(self, execution.state());
+
+            match execution.next() {
+                Some(step) => observer.after_step(self, &step),
+                // A partial transition function halts the machine where it is not defined
+                None if execution.error() == Some(&Error::UndefinedTransition) => break,
+                None => panic!(
+                    "{}",
+                    execution
+                        .error()
+                        .expect("Execution stopped without an error")
+                ),
+            }
+        }
+
+        observer.on_halt(self, execution.state(), execution.steps());
+        execution.into_state()
+    }
+}
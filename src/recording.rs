@@ -0,0 +1,167 @@
+use crate::{EndStates, Error, Move, TuringStates, TuringTape};
+
+/// What is needed to undo a single step
+#[derive(Debug, Clone)]
+struct Undo<Alphabet, S> {
+    /// The state before the step
+    state: S,
+    /// The token at the cursor before the step
+    token: Alphabet,
+    /// The move of the cursor made by the step
+    mv: Move,
+    /// Whether the step added a cell to the tape
+    grew: bool,
+}
+
+/// A run of a turing machine which can be stepped backward and forward through its history
+///
+/// Only the overwritten token, the previous state, the move and whether the tape grew are stored
+/// per step, so the tape is never copied. Stepping back removes the cells a step added.
+/// Stepping forward over a step which has been taken before executes the transition again.
+/// Created with [`TuringTape::record`].
+///
+/// ```
+/// use turingmachine_rs::{Move, TransitionTable, TuringTape};
+///
+/// let table = TransitionTable::new("scan")
+///     .transition("scan", '1', "scan", '0', Move::Right)
+///     .transition("scan", '_', "done", '_', Move::Stay)
+///     .accept("done");
+///
+/// let tape = TuringTape::new('_', '1', vec!['1']);
+/// let mut recording = tape.record(table.start(), table.end_states());
+///
+/// recording.run().unwrap();
+/// assert_eq!(recording.steps(), 3);
+/// assert_eq!(tape.to_compact_string(), "00[_]");
+///
+/// recording.goto(1).unwrap();
+/// assert_eq!(tape.to_compact_string(), "0[1]");
+/// assert_eq!(recording.state().state(), &"scan");
+/// ```
+pub struct Recording<'a, Alphabet, S, E = Vec<S>> {
+    /// The tape the machine runs on
+    tape: &'a TuringTape<Alphabet>,
+    /// The current state
+    state: S,
+    /// The states in which the machine halts
+    end_states: E,
+    /// The undo information of all recorded steps
+    history: Vec<Undo<Alphabet, S>>,
+    /// The amount of steps taken to get to the current point in the history
+    index: usize,
+}
+
+impl<'a, Alphabet: Clone, S: TuringStates<Alphabet> + Clone, E: EndStates<S>>
+    Recording<'a, Alphabet, S, E>
+{
+    /// Fetch the current state
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    /// Fetch the tape the machine runs on
+    pub fn tape(&self) -> &'a TuringTape<Alphabet> {
+        self.tape
+    }
+
+    /// Fetch the amount of steps taken to get to the current point in the history
+    pub fn steps(&self) -> usize {
+        self.index
+    }
+
+    /// Fetch the amount of steps which have been recorded
+    pub fn recorded(&self) -> usize {
+        self.history.len()
+    }
+
+    /// Returns whether one of the end states has been reached
+    pub fn is_halted(&self) -> bool {
+        self.end_states.is_halting(&self.state)
+    }
+
+    /// Take one step forward. Will return whether a step was taken, which is not the case once
+    /// an end state has been reached.
+    ///
+    /// Will return an error if the step cannot be executed. The state and the tape are then
+    /// left untouched.
+    pub fn step_forward(&mut self) -> Result<bool, Error> {
+        if self.is_halted() {
+            return Ok(false);
+        }
+
+        let state = self.state.clone();
+        let token = self.tape.get_cursor();
+        let (mv, grew) = self.tape.apply_step(&mut self.state)?;
+
+        if self.index == self.history.len() {
+            self.history.push(Undo {
+                state,
+                token,
+                mv,
+                grew,
+            });
+        }
+
+        self.index += 1;
+        Ok(true)
+    }
+
+    /// Take one step backward, undoing the last step. Will return whether a step was undone,
+    /// which is not the case at the start of the run.
+    pub fn step_back(&mut self) -> bool {
+        if self.index == 0 {
+            return false;
+        }
+
+        self.index -= 1;
+        let undo = &self.history[self.index];
+
+        self.tape.undo_move(undo.mv, undo.grew);
+        self.tape.set_cursor(undo.token.clone());
+        self.state = undo.state.clone();
+
+        true
+    }
+
+    /// Step backward or forward until `step` steps have been taken. Will return whether that
+    /// step has been reached, which is not the case if an end state has been reached before.
+    pub fn goto(&mut self, step: usize) -> Result<bool, Error> {
+        while self.index > step {
+            self.step_back();
+        }
+
+        while self.index < step {
+            if !self.step_forward()? {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Step forward until one of the end states has been reached
+    pub fn run(&mut self) -> Result<(), Error> {
+        while self.step_forward()? {}
+
+        Ok(())
+    }
+}
+
+impl<Alphabet: Clone> TuringTape<Alphabet> {
+    /// Start a recording of the machine running from the start state, which can be stepped
+    /// through until one of the end states has been reached.
+    pub fn record<S: TuringStates<Alphabet> + Clone, E: EndStates<S>>(
+        &self,
+        start_state: S,
+        end_states: E,
+    ) -> Recording<'_, Alphabet, S, E> {
+        Recording {
+            tape: self,
+            state: start_state,
+            end_states,
+            history: Vec::new(),
+            index: 0,
+        }
+    }
+}
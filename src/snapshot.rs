@@ -0,0 +1,53 @@
+use crate::TuringTape;
+
+/// A copy of the contents and cursor position of a [`TuringTape`]
+///
+/// Snapshots can be compared and hashed, so they can be used to recognize configurations of a
+/// machine which have been seen before.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TapeSnapshot<Alphabet> {
+    /// The cells of the tape, from the leftmost to the rightmost
+    cells: Vec<Alphabet>,
+    /// The index of the cursor within the cells
+    cursor: usize,
+    /// The index of the first cell of the tape at creation within the cells
+    origin: usize,
+}
+
+impl<Alphabet> TapeSnapshot<Alphabet> {
+    /// Fetch the cells of the tape, from the leftmost to the rightmost
+    pub fn cells(&self) -> &[Alphabet] {
+        &self.cells
+    }
+
+    /// Fetch the token at the cursor
+    pub fn get_cursor(&self) -> &Alphabet {
+        &self.cells[self.cursor]
+    }
+
+    /// Fetch the position of the cursor relative to the first cell of the tape at creation
+    pub fn position(&self) -> isize {
+        self.cursor as isize - self.origin as isize
+    }
+}
+
+impl<Alphabet: Clone> TuringTape<Alphabet> {
+    /// Take a snapshot of the contents and cursor position of the tape
+    pub fn snapshot(&self) -> TapeSnapshot<Alphabet> {
+        TapeSnapshot {
+            cells: self.cells.borrow().iter().cloned().collect(),
+            cursor: self.cursor.get(),
+            origin: self.origin.get(),
+        }
+    }
+
+    /// Put the contents and cursor position of a snapshot back onto the tape
+    ///
+    /// The snapshot can be restored any number of times, also onto a clone of the tape it was
+    /// taken from.
+    pub fn restore(&self, snapshot: &TapeSnapshot<Alphabet>) {
+        *self.cells.borrow_mut() = snapshot.cells.iter().cloned().collect();
+        self.cursor.set(snapshot.cursor);
+        self.origin.set(snapshot.origin);
+    }
+}
@@ -0,0 +1,158 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+use crate::{EndStates, Observer, Step, TuringStates, TuringTape};
+
+/// Statistics about a run of a turing machine
+///
+/// Collected by using it as an [`Observer`], or returned by
+/// [`TuringTape::run_states_with_stats`].
+#[derive(Debug, Clone)]
+pub struct RunStats<Alphabet, S> {
+    /// The amount of steps taken
+    steps: usize,
+    /// The positions of all cells the cursor has been on
+    visited: HashSet<isize>,
+    /// The leftmost position of the cursor
+    leftmost: isize,
+    /// The rightmost position of the cursor
+    rightmost: isize,
+    /// The amount of times the machine was in each state
+    state_visits: HashMap<S, usize>,
+    /// The amount of times each transition, identified by state and read token, has been used
+    transition_usage: HashMap<(S, Alphabet), usize>,
+    /// The amount of writes which changed the token at the cursor
+    changed_writes: usize,
+}
+
+impl<Alphabet, S> Default for RunStats<Alphabet, S> {
+    fn default() -> Self {
+        RunStats {
+            steps: 0,
+            visited: HashSet::new(),
+            leftmost: 0,
+            rightmost: 0,
+            state_visits: HashMap::new(),
+            transition_usage: HashMap::new(),
+            changed_writes: 0,
+        }
+    }
+}
+
+impl<Alphabet: Eq + Hash, S: Eq + Hash> RunStats<Alphabet, S> {
+    /// Initialize new empty statistics
+    pub fn new() -> RunStats<Alphabet, S> {
+        RunStats::default()
+    }
+
+    /// Fetch the amount of steps taken
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// Fetch the amount of distinct cells the cursor has been on
+    pub fn cells_visited(&self) -> usize {
+        self.visited.len()
+    }
+
+    /// Fetch the leftmost position of the cursor, relative to the first cell of the tape at
+    /// creation
+    pub fn leftmost(&self) -> isize {
+        self.leftmost
+    }
+
+    /// Fetch the rightmost position of the cursor, relative to the first cell of the tape at
+    /// creation
+    pub fn rightmost(&self) -> isize {
+        self.rightmost
+    }
+
+    /// Fetch the amount of times the machine was in a state, including the state it halted in
+    pub fn state_visits(&self, state: &S) -> usize {
+        self.state_visits.get(state).copied().unwrap_or(0)
+    }
+
+    /// Fetch the amount of times the machine was in each state, including the state it halted
+    /// in
+    pub fn all_state_visits(&self) -> &HashMap<S, usize> {
+        &self.state_visits
+    }
+
+    /// Fetch the amount of times the transition for a state and read token has been used
+    pub fn transition_usage(&self, state: &S, token: &Alphabet) -> usize
+    where
+        S: Clone,
+        Alphabet: Clone,
+    {
+        self.transition_usage
+            .get(&(state.clone(), token.clone()))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Fetch the amount of times each transition, identified by state and read token, has been
+    /// used
+    pub fn all_transition_usage(&self) -> &HashMap<(S, Alphabet), usize> {
+        &self.transition_usage
+    }
+
+    /// Fetch the amount of writes which changed the token at the cursor
+    pub fn changed_writes(&self) -> usize {
+        self.changed_writes
+    }
+
+    /// Register the cursor being on a position
+    fn visit(&mut self, position: isize) {
+        if self.visited.is_empty() {
+            self.leftmost = position;
+            self.rightmost = position;
+        }
+
+        self.visited.insert(position);
+        self.leftmost = self.leftmost.min(position);
+        self.rightmost = self.rightmost.max(position);
+    }
+}
+
+impl<Alphabet: Clone + Eq + Hash, S: Clone + Eq + Hash> Observer<Alphabet, S>
+    for RunStats<Alphabet, S>
+{
+    fn before_step(&mut self, tape: &TuringTape<Alphabet>, _state: &S) {
+        self.visit(tape.position());
+    }
+
+    fn after_step(&mut self, _tape: &TuringTape<Alphabet>, step: &Step<Alphabet, S>) {
+        self.steps += 1;
+        self.visit(step.position);
+
+        *self.state_visits.entry(step.from.clone()).or_insert(0) += 1;
+        *self
+            .transition_usage
+            .entry((step.from.clone(), step.read.clone()))
+            .or_insert(0) += 1;
+
+        if step.read != step.written {
+            self.changed_writes += 1;
+        }
+    }
+
+    fn on_halt(&mut self, tape: &TuringTape<Alphabet>, state: &S, _steps: usize) {
+        self.visit(tape.position());
+        *self.state_visits.entry(state.clone()).or_insert(0) += 1;
+    }
+}
+
+impl<Alphabet: Clone + Eq + Hash> TuringTape<Alphabet> {
+    /// Runs from start state until one of the end states has been reached, collecting
+    /// statistics along the way.
+    /// Will return the end state and the statistics of the run.
+    pub fn run_states_with_stats<S: TuringStates<Alphabet> + Clone + Eq + Hash, E: EndStates<S>>(
+        &self,
+        start_state: S,
+        end_states: E,
+    ) -> (S, RunStats<Alphabet, S>) {
+        let mut stats = RunStats::new();
+        let end_state = self.run_states_observed(start_state, end_states, &mut stats);
+        (end_state, stats)
+    }
+}
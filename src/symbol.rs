@@ -0,0 +1,47 @@
+use crate::Error;
+
+/// A token of a tape alphabet which is written as a single character
+///
+/// Usually implemented with `#[derive(TapeSymbol)]` using the `derive` feature, which also
+/// generates the `Display`, `FromStr` and `TryFrom<char>` implementations.
+///
+/// ```
+/// # #[cfg(feature = "derive")]
+/// # {
+/// use turingmachine_rs::TapeSymbol;
+///
+/// #[derive(TapeSymbol, Clone, Debug, PartialEq)]
+/// enum Bit {
+///     #[symbol('_')]
+///     Delta,
+///     #[symbol('0')]
+///     Zero,
+///     #[symbol('1')]
+///     One,
+/// }
+///
+/// assert_eq!(Bit::parse_symbols("_01").unwrap(), Bit::symbols());
+/// assert_eq!(Bit::One.to_string(), "1");
+/// assert_eq!("0".parse::<Bit>(), Ok(Bit::Zero));
+/// # }
+/// ```
+pub trait TapeSymbol: Sized {
+    /// Fetch the character of the symbol
+    fn to_char(&self) -> char;
+
+    /// Fetch the symbol belonging to a character, if there is one
+    fn from_char(c: char) -> Option<Self>;
+
+    /// Fetch all the symbols of the alphabet in declaration order
+    fn symbols() -> Vec<Self>;
+
+    /// Parse a string into symbols, one character per symbol
+    fn parse_symbols(s: &str) -> Result<Vec<Self>, Error> {
+        s.chars()
+            .map(|c| {
+                Self::from_char(c)
+                    .ok_or_else(|| Error::Format(format!("unknown tape symbol '{}'", c)))
+            })
+            .collect()
+    }
+}
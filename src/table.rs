@@ -0,0 +1,203 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+
+use crate::{Move, PartialTuringStates};
+
+/// A data-driven turing machine, mapping each state and the tokens read to a transition
+///
+/// The tokens and moves are single values for a [`TransitionTable`] and arrays with one entry
+/// per tape for a [`MultiTapeTable`](crate::MultiTapeTable).
+#[derive(Debug, Clone)]
+pub struct Table<State, Tokens, Moves> {
+    /// The transitions for each state and tokens
+    transitions: HashMap<(State, Tokens), (State, Tokens, Moves)>,
+    /// The state to start in
+    start: State,
+    /// The end states that accept the input
+    accept: Vec<State>,
+    /// The end states that reject the input
+    reject: Vec<State>,
+}
+
+/// A data-driven turing machine, mapping each state and token to a transition
+///
+/// States and tokens can be any hashable values, so machines can be constructed at runtime. A
+/// table is built by chaining calls:
+///
+/// ```
+/// use turingmachine_rs::{Move, TransitionTable, TuringTape};
+///
+/// let table = TransitionTable::new("scan")
+///     .transition("scan", '0', "scan", '0', Move::Right)
+///     .transition("scan", '1', "scan", '0', Move::Right)
+///     .transition("scan", '_', "done", '_', Move::Stay)
+///     .accept("done");
+///
+/// let tape = TuringTape::new('_', '1', vec!['0', '1']);
+/// let end_state = tape.run_states(table.start(), table.end_states());
+///
+/// assert!(end_state.is_accepting());
+/// assert_eq!(<Vec<char>>::from(tape), vec!['0', '0', '0', '_']);
+/// ```
+pub type TransitionTable<State, Alphabet> = Table<State, Alphabet, Move>;
+
+impl<State: Clone + Eq + Hash, Tokens: Clone + Eq + Hash, Moves> Table<State, Tokens, Moves> {
+    /// Initialize a new table without any transitions, starting in the `start` state
+    pub fn new(start: State) -> Table<State, Tokens, Moves> {
+        Table {
+            transitions: HashMap::new(),
+            start,
+            accept: Vec::new(),
+            reject: Vec::new(),
+        }
+    }
+
+    /// Add the transition taken when reading `read` in the `from` state. This replaces any
+    /// earlier transition for the same state and tokens.
+    pub fn transition(
+        mut self,
+        from: State,
+        read: Tokens,
+        to: State,
+        write: Tokens,
+        mv: Moves,
+    ) -> Table<State, Tokens, Moves> {
+        self.transitions.insert((from, read), (to, write, mv));
+        self
+    }
+
+    /// Add an end state which accepts the input
+    pub fn accept(mut self, state: State) -> Table<State, Tokens, Moves> {
+        self.accept.push(state);
+        self
+    }
+
+    /// Add an end state which rejects the input
+    pub fn reject(mut self, state: State) -> Table<State, Tokens, Moves> {
+        self.reject.push(state);
+        self
+    }
+
+    /// Fetch the transition taken when reading `tokens` in `state`
+    pub fn get(&self, state: &State, tokens: &Tokens) -> Option<&(State, Tokens, Moves)> {
+        // The key has to be owned to look it up in the map
+        self.transitions.get(&(state.clone(), tokens.clone()))
+    }
+
+    /// Fetch the state to start in
+    pub fn start_state(&self) -> &State {
+        &self.start
+    }
+
+    /// Fetch the end states that accept the input
+    pub fn accept_states(&self) -> &[State] {
+        &self.accept
+    }
+
+    /// Fetch the end states that reject the input
+    pub fn reject_states(&self) -> &[State] {
+        &self.reject
+    }
+
+    /// Iterate over all transitions, as the state and read tokens with the transition taken
+    pub fn transitions(&self) -> impl Iterator<Item = (&(State, Tokens), &(State, Tokens, Moves))> {
+        self.transitions.iter()
+    }
+
+    /// Fetch the start state bound to this table, to be used in
+    /// [`TuringTape::run_states`](crate::TuringTape::run_states),
+    /// [`MultiTapeMachine::run_states`](crate::MultiTapeMachine::run_states) and friends
+    pub fn start(&self) -> BoundState<'_, State, Tokens, Moves> {
+        self.state(self.start.clone())
+    }
+
+    /// Fetch the accepting and rejecting end states bound to this table
+    pub fn end_states(&self) -> Vec<BoundState<'_, State, Tokens, Moves>> {
+        self.accept
+            .iter()
+            .chain(self.reject.iter())
+            .map(|state| self.state(state.clone()))
+            .collect()
+    }
+
+    /// Bind a state to this table
+    pub fn state(&self, state: State) -> BoundState<'_, State, Tokens, Moves> {
+        BoundState { table: self, state }
+    }
+}
+
+/// A state of a [`Table`], which runs by looking up its transitions in the table
+pub struct BoundState<'a, State, Tokens, Moves> {
+    /// The table containing the transitions
+    table: &'a Table<State, Tokens, Moves>,
+    /// The actual state
+    state: State,
+}
+
+/// A state of a [`TransitionTable`], which implements [`TuringStates`](crate::TuringStates)
+/// by looking up its transitions in the table
+pub type TableState<'a, State, Alphabet> = BoundState<'a, State, Alphabet, Move>;
+
+impl<'a, State: Clone + Eq + Hash, Tokens: Clone + Eq + Hash, Moves>
+    BoundState<'a, State, Tokens, Moves>
+{
+    /// Fetch the actual state
+    pub fn state(&self) -> &State {
+        &self.state
+    }
+
+    /// Take the actual state
+    pub fn into_state(self) -> State {
+        self.state
+    }
+
+    /// Returns whether this is one of the accepting end states of the table
+    pub fn is_accepting(&self) -> bool {
+        self.table.accept.contains(&self.state)
+    }
+
+    /// Returns whether this is one of the rejecting end states of the table
+    pub fn is_rejecting(&self) -> bool {
+        self.table.reject.contains(&self.state)
+    }
+
+    /// Look up the transition for the read tokens, with the next state bound to the table
+    pub(crate) fn lookup(&self, tokens: &Tokens) -> Option<(Self, Tokens, Moves)>
+    where
+        Moves: Copy,
+    {
+        self.table
+            .get(&self.state, tokens)
+            .map(|(state, tokens, mv)| (self.table.state(state.clone()), tokens.clone(), *mv))
+    }
+}
+
+impl<'a, State: Clone, Tokens, Moves> Clone for BoundState<'a, State, Tokens, Moves> {
+    fn clone(&self) -> Self {
+        BoundState {
+            table: self.table,
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl<'a, State: PartialEq, Tokens, Moves> PartialEq for BoundState<'a, State, Tokens, Moves> {
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state
+    }
+}
+
+impl<'a, State: fmt::Debug, Tokens, Moves> fmt::Debug for BoundState<'a, State, Tokens, Moves> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.state.fmt(f)
+    }
+}
+
+impl<'a, State: Clone + Eq + Hash, Alphabet: Clone + Eq + Hash> PartialTuringStates<Alphabet>
+    for TableState<'a, State, Alphabet>
+{
+    fn partial_step(&self, current_token: Alphabet) -> Option<(Self, Alphabet, Move)> {
+        self.lookup(&current_token)
+    }
+}
@@ -0,0 +1,127 @@
+use std::fmt::{self, Write};
+
+use crate::{Error, TuringTape};
+
+impl<Alphabet: Clone> TuringTape<Alphabet> {
+    /// Initialize a new TuringTape from a string with:
+    ///
+    /// - __s:__ The cells of the tape, one character per cell. The first cell is the start
+    ///   token, and one cell may be surrounded by `[` and `]` to place the cursor on it.
+    /// - __empty:__ The token put at empty tape cells
+    /// - __parse:__ Converts a character into a token
+    ///
+    /// Without a marked cell the cursor starts on the first cell, like with
+    /// [`TuringTape::new`]. The tape is not two-way infinite, see
+    /// [`TuringTape::from_str_with_two_way`] for that.
+    ///
+    /// ```
+    /// use turingmachine_rs::TuringTape;
+    ///
+    /// let tape = TuringTape::from_str_with("_0[1]1", '_', Some).unwrap();
+    /// assert_eq!(tape.get_cursor(), '1');
+    /// assert_eq!(tape.to_compact_string(), "_0[1]1");
+    /// ```
+    pub fn from_str_with<F>(s: &str, empty: Alphabet, parse: F) -> Result<Self, Error>
+    where
+        F: FnMut(char) -> Option<Alphabet>,
+    {
+        let (start, initial, cursor) = parse_compact(s, parse)?;
+        Ok(TuringTape::new(empty, start, initial).with_cursor(cursor))
+    }
+
+    /// Initialize a new two-way infinite TuringTape from a string, like
+    /// [`TuringTape::from_str_with`] does for tapes like [`TuringTape::new_two_way`]
+    ///
+    /// ```
+    /// use turingmachine_rs::TuringTape;
+    ///
+    /// let tape = TuringTape::from_str_with_two_way("[0]1", '_', Some).unwrap();
+    /// tape.step_left();
+    /// assert_eq!(tape.to_compact_string(), "[_]01");
+    /// ```
+    pub fn from_str_with_two_way<F>(s: &str, empty: Alphabet, parse: F) -> Result<Self, Error>
+    where
+        F: FnMut(char) -> Option<Alphabet>,
+    {
+        let (start, initial, cursor) = parse_compact(s, parse)?;
+        Ok(TuringTape::new_two_way(empty, start, initial).with_cursor(cursor))
+    }
+}
+
+/// Parse the cells of a tape written as a string, returning the first cell, the other cells and
+/// the index of the cell at the cursor
+fn parse_compact<Alphabet, F>(
+    s: &str,
+    mut parse: F,
+) -> Result<(Alphabet, Vec<Alphabet>, usize), Error>
+where
+    F: FnMut(char) -> Option<Alphabet>,
+{
+    let mut cells = Vec::with_capacity(s.len());
+    let mut cursor = None;
+    let mut marking = false;
+
+    for c in s.chars() {
+        match c {
+            '[' if cursor.is_some() => {
+                return Err(Error::Format(format!(
+                    "more than one cursor marked in '{}'",
+                    s
+                )))
+            }
+            '[' => {
+                marking = true;
+                cursor = Some(cells.len());
+            }
+            ']' if marking && cursor.map(|index| index + 1) == Some(cells.len()) => marking = false,
+            ']' => {
+                return Err(Error::Format(format!(
+                    "cursor marker does not surround a single cell in '{}'",
+                    s
+                )))
+            }
+            _ if marking && cursor != Some(cells.len()) => {
+                return Err(Error::Format(format!(
+                    "cursor marker does not surround a single cell in '{}'",
+                    s
+                )))
+            }
+            _ => cells.push(
+                parse(c).ok_or_else(|| Error::Format(format!("unknown tape symbol '{}'", c)))?,
+            ),
+        }
+    }
+
+    if marking {
+        return Err(Error::Format(format!("unclosed cursor marker in '{}'", s)));
+    }
+
+    let mut cells = cells.into_iter();
+    let start = cells
+        .next()
+        .ok_or_else(|| Error::Format("a tape needs at least one cell".to_string()))?;
+
+    Ok((start, cells.collect(), cursor.unwrap_or(0)))
+}
+
+impl<Alphabet: fmt::Display> TuringTape<Alphabet> {
+    /// Render the cells of the tape without separators, surrounding the cell at the cursor by
+    /// `[` and `]`
+    ///
+    /// This is the inverse of [`TuringTape::from_str_with`] for tokens which display as a
+    /// single character.
+    pub fn to_compact_string(&self) -> String {
+        let mut s = String::new();
+
+        for (index, token) in self.cells.borrow().iter().enumerate() {
+            // Writing into a String cannot fail
+            if index == self.cursor.get() {
+                let _ = write!(s, "[{}]", token);
+            } else {
+                let _ = write!(s, "{}", token);
+            }
+        }
+
+        s
+    }
+}
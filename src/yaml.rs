@@ -0,0 +1,315 @@
+//! The YAML machine descriptions of the [turingmachine.io](https://turingmachine.io) simulator
+//!
+//! ```yaml
+//! input: '1011'
+//! blank: ' '
+//! start state: right
+//! table:
+//!   right:
+//!     [1,0]: R
+//!     ' '  : {L: carry}
+//!   carry:
+//!     1      : {write: 0, L}
+//!     [0,' ']: {write: 1, L: done}
+//!   done:
+//! ```
+//!
+//! Every state maps symbols, or lists of symbols, to a rule. A rule is either just a move `L`
+//! or `R`, or a map with an optional symbol to `write` and a move, optionally mapping to the
+//! next state. The machine halts once no rule is defined.
+
+use yaml_rust2::yaml::Hash;
+use yaml_rust2::{Yaml, YamlEmitter, YamlLoader};
+
+use crate::{Error, Move, TransitionTable, TuringTape};
+
+impl TransitionTable<String, char> {
+    /// Load a machine and its initial tape from a turingmachine.io YAML description
+    ///
+    /// All states without any rules are accepting end states. The tape is two-way infinite,
+    /// with the cursor on the first symbol of the input.
+    ///
+    /// Will return an error for malformed descriptions and constructs which cannot be
+    /// represented, such as symbols of more than one character.
+    pub fn from_turingmachine_io(
+        yaml: &str,
+    ) -> Result<(TransitionTable<String, char>, TuringTape<char>), Error> {
+        let documents =
+            YamlLoader::load_from_str(yaml).map_err(|err| Error::Format(err.to_string()))?;
+        let document = match documents.as_slice() {
+            [document] => document,
+            _ => return Err(Error::Format("expected a single YAML document".to_string())),
+        };
+        let document = document
+            .as_hash()
+            .ok_or_else(|| Error::Format("expected a map at the top level".to_string()))?;
+
+        let mut blank = None;
+        let mut start = None;
+        let mut input = String::new();
+        let mut rules = None;
+
+        for (key, value) in document {
+            match key.as_str() {
+                Some("blank") => blank = Some(symbol(value)?),
+                Some("start state") => start = Some(scalar(value)?),
+                Some("input") => input = scalar(value)?,
+                Some("table") => {
+                    rules =
+                        Some(value.as_hash().ok_or_else(|| {
+                            Error::Format("expected 'table' to be a map".to_string())
+                        })?)
+                }
+                _ => return Err(Error::Format(format!("unsupported key {:?}", key))),
+            }
+        }
+
+        let blank = blank.ok_or_else(|| Error::Format("missing 'blank'".to_string()))?;
+        let start = start.ok_or_else(|| Error::Format("missing 'start state'".to_string()))?;
+        let rules = rules.ok_or_else(|| Error::Format("missing 'table'".to_string()))?;
+
+        let mut table = TransitionTable::new(start.clone());
+        let mut halting = Vec::new();
+        let mut declared = Vec::new();
+
+        for (state, state_rules) in rules {
+            let state = scalar(state)?;
+            declared.push(state.clone());
+
+            let state_rules = match state_rules {
+                Yaml::Null => {
+                    halting.push(state);
+                    continue;
+                }
+                Yaml::Hash(state_rules) if state_rules.is_empty() => {
+                    halting.push(state);
+                    continue;
+                }
+                Yaml::Hash(state_rules) => state_rules,
+                _ => {
+                    return Err(Error::Format(format!(
+                        "expected the rules of state '{}' to be a map",
+                        state
+                    )))
+                }
+            };
+
+            for (symbols, rule) in state_rules {
+                let (write, mv, next) = parse_rule(rule)?;
+                let next = next.unwrap_or_else(|| state.clone());
+
+                let symbols = match symbols {
+                    Yaml::Array(symbols) => symbols.iter().map(symbol).collect(),
+                    symbols => symbol(symbols).map(|symbol| vec![symbol]),
+                }?;
+
+                for read in symbols {
+                    table = table.transition(
+                        state.clone(),
+                        read,
+                        next.clone(),
+                        write.unwrap_or(read),
+                        mv,
+                    );
+                }
+            }
+        }
+
+        let undeclared = table
+            .transitions()
+            .map(|(_, (next, _, _))| next)
+            .chain(std::iter::once(&start))
+            .find(|state| !declared.contains(state));
+        if let Some(state) = undeclared {
+            return Err(Error::Format(format!("undeclared state '{}'", state)));
+        }
+
+        let table = halting
+            .into_iter()
+            .fold(table, |table, state| table.accept(state));
+
+        let mut input = input.chars();
+        let tape = TuringTape::new_two_way(blank, input.next().unwrap_or(blank), input.collect());
+
+        Ok((table, tape))
+    }
+
+    /// Export the machine and a tape into a turingmachine.io YAML description
+    ///
+    /// The input is taken from the cursor up to the last symbol which is not blank. As states
+    /// without rules are read back as accepting, exactly the accepting states have to be without
+    /// transitions. Will return an error for constructs which cannot be represented, such as
+    /// [`Move::Stay`], rejecting states or symbols left of the cursor.
+    pub fn to_turingmachine_io(&self, tape: &TuringTape<char>) -> Result<String, Error> {
+        let blank = tape.empty;
+        let cells = tape.cells.borrow();
+        let (left, right) = cells.as_slices();
+        let cells: Vec<char> = left.iter().chain(right.iter()).copied().collect();
+        let cursor = tape.cursor.get();
+
+        if cells[..cursor].iter().any(|symbol| *symbol != blank) {
+            return Err(Error::Format(
+                "the tape contains symbols left of the cursor".to_string(),
+            ));
+        }
+        if let Some(state) = self.reject_states().first() {
+            return Err(Error::Format(format!(
+                "rejecting state '{}' cannot be represented",
+                state
+            )));
+        }
+
+        let input: String = cells[cursor..]
+            .iter()
+            .collect::<String>()
+            .trim_end_matches(blank)
+            .to_string();
+
+        // Order the states with the start state first, so the description reads top to bottom
+        let mut states: Vec<&String> = self
+            .transitions()
+            .flat_map(|((state, _), (next, _, _))| vec![state, next])
+            .chain(self.accept_states())
+            .chain(self.reject_states())
+            .filter(|state| *state != self.start_state())
+            .collect();
+        states.sort();
+        states.dedup();
+        states.insert(0, self.start_state());
+
+        let mut rules = Hash::new();
+        for state in states {
+            let mut transitions: Vec<_> = self
+                .transitions()
+                .filter(|((from, _), _)| from == state)
+                .collect();
+            transitions.sort_by_key(|((_, read), _)| *read);
+
+            let mut state_rules = Hash::new();
+            for ((_, read), (next, write, mv)) in transitions {
+                let mv = match mv {
+                    Move::Left => "L",
+                    Move::Right => "R",
+                    Move::Stay => {
+                        return Err(Error::Format(format!(
+                            "the rule of state '{}' on '{}' does not move",
+                            state, read
+                        )))
+                    }
+                };
+
+                let mut rule = Hash::new();
+                if write != read {
+                    rule.insert(string("write"), string(&write.to_string()));
+                }
+                rule.insert(string(mv), string(next));
+                state_rules.insert(string(&read.to_string()), Yaml::Hash(rule));
+            }
+
+            let accepting = self.accept_states().contains(state);
+            if accepting && !state_rules.is_empty() {
+                return Err(Error::Format(format!(
+                    "accepting state '{}' has rules",
+                    state
+                )));
+            }
+            if !accepting && state_rules.is_empty() {
+                return Err(Error::Format(format!(
+                    "state '{}' would be accepting without rules",
+                    state
+                )));
+            }
+
+            let state_rules = if state_rules.is_empty() {
+                Yaml::Null
+            } else {
+                Yaml::Hash(state_rules)
+            };
+            rules.insert(string(state), state_rules);
+        }
+
+        let mut document = Hash::new();
+        document.insert(string("input"), string(&input));
+        document.insert(string("blank"), string(&blank.to_string()));
+        document.insert(string("start state"), string(self.start_state()));
+        document.insert(string("table"), Yaml::Hash(rules));
+
+        let mut yaml = String::new();
+        YamlEmitter::new(&mut yaml)
+            .dump(&Yaml::Hash(document))
+            .map_err(|err| Error::Format(format!("{:?}", err)))?;
+
+        // Drop the document start marker
+        Ok(yaml.trim_start_matches("---\n").to_string() + "\n")
+    }
+}
+
+/// Parse a rule into the symbol to write, the move and the next state
+fn parse_rule(rule: &Yaml) -> Result<(Option<char>, Move, Option<String>), Error> {
+    match rule {
+        Yaml::String(mv) => Ok((None, parse_move(mv)?, None)),
+        Yaml::Hash(rule) => {
+            let mut write = None;
+            let mut mv = None;
+
+            for (key, value) in rule {
+                match key.as_str() {
+                    Some("write") => write = Some(symbol(value)?),
+                    Some(key @ "L") | Some(key @ "R") if mv.is_none() => {
+                        let next = match value {
+                            Yaml::Null => None,
+                            next => Some(scalar(next)?),
+                        };
+                        mv = Some((parse_move(key)?, next));
+                    }
+                    _ => return Err(Error::Format(format!("unsupported rule key {:?}", key))),
+                }
+            }
+
+            let (mv, next) = mv.ok_or_else(|| Error::Format("rule without a move".to_string()))?;
+            Ok((write, mv, next))
+        }
+        _ => Err(Error::Format(format!("unsupported rule {:?}", rule))),
+    }
+}
+
+/// Parse the `L` and `R` moves
+fn parse_move(mv: &str) -> Result<Move, Error> {
+    match mv {
+        "L" => Ok(Move::Left),
+        "R" => Ok(Move::Right),
+        _ => Err(Error::Format(format!("unsupported move '{}'", mv))),
+    }
+}
+
+/// Read a scalar value, such as a state name, as a string
+fn scalar(value: &Yaml) -> Result<String, Error> {
+    match value {
+        Yaml::String(value) | Yaml::Real(value) => Ok(value.clone()),
+        Yaml::Integer(value) => Ok(value.to_string()),
+        Yaml::Boolean(value) => Ok(value.to_string()),
+        _ => Err(Error::Format(format!(
+            "expected a scalar, found {:?}",
+            value
+        ))),
+    }
+}
+
+/// Read a single character symbol
+fn symbol(value: &Yaml) -> Result<char, Error> {
+    let value = scalar(value)?;
+    let mut chars = value.chars();
+
+    match (chars.next(), chars.next()) {
+        (Some(symbol), None) => Ok(symbol),
+        _ => Err(Error::Format(format!(
+            "symbol '{}' is not a single character",
+            value
+        ))),
+    }
+}
+
+/// Create a YAML string
+fn string(value: &str) -> Yaml {
+    Yaml::String(value.to_string())
+}
@@ -0,0 +1,76 @@
+//! In this integration test a turing machine is created which increments a binary number. The
+//! transition function is partial, the machine halts once no transition is defined.
+
+use turingmachine_rs::*;
+
+/// The Alphabet Used
+#[derive(PartialEq, Clone, Debug)]
+enum Alphabet {
+    Blank,
+    Zero,
+    One,
+}
+
+/// All the different states
+#[derive(PartialEq, Clone, Debug)]
+enum States {
+    Right,
+    Carry,
+    Done,
+}
+
+/// The implementation for the states
+impl PartialTuringStates<Alphabet> for States {
+    fn partial_step(&self, t: Alphabet) -> Option<(Self, Alphabet, Move)> {
+        use Alphabet::*;
+        use States::*;
+
+        match (self, t) {
+            (Right, Blank) => Some((Carry, Blank, Move::Left)),
+            (Right, t) => Some((Right, t, Move::Right)),
+
+            (Carry, One) => Some((Carry, Zero, Move::Left)),
+            (Carry, _) => Some((Done, One, Move::Left)),
+
+            (Done, _) => None,
+        }
+    }
+}
+
+#[test]
+fn binary_increment_proper_output() {
+    use Alphabet::*;
+    use States::*;
+
+    let tape = TuringTape::new_two_way(Blank, One, vec![Zero, One, One]);
+    assert_eq!(
+        tape.run_states_limited(Right, vec![], 100),
+        RunOutcome::UndefinedTransition {
+            state: Done,
+            steps: 8
+        }
+    );
+    assert_eq!(
+        <Vec<Alphabet>>::from(tape),
+        vec![One, One, Zero, Zero, Blank]
+    );
+
+    let tape = TuringTape::new_two_way(Blank, One, vec![One]);
+    assert_eq!(
+        tape.try_run_states(Right, vec![]),
+        Err(Error::UndefinedTransition)
+    );
+    assert_eq!(
+        <Vec<Alphabet>>::from(tape),
+        vec![Blank, One, Zero, Zero, Blank]
+    );
+
+    let tape = TuringTape::new_two_way(Blank, One, vec![One, One]);
+    let mut counter = StepCounter::default();
+    assert_eq!(tape.run_states_observed(Right, vec![], &mut counter), Done);
+    assert_eq!(counter.steps, 8);
+    assert_eq!(
+        <Vec<Alphabet>>::from(tape),
+        vec![Blank, One, Zero, Zero, Zero, Blank]
+    );
+}
@@ -0,0 +1,97 @@
+//! In this integration test busy beaver champions are parsed from the standard text format of
+//! the busy beaver challenge and run.
+
+use turingmachine_rs::*;
+
+/// Run a busy beaver on an empty tape, returning the amount of steps and `1`s written
+fn run(text: &str) -> (usize, usize) {
+    let table = TransitionTable::from_bbchallenge(text).unwrap();
+    let tape = TuringTape::new_two_way(0, 0, vec![]);
+
+    let outcome = tape.run_states_limited(table.start(), table.end_states(), 1_000);
+    assert!(outcome.is_halted());
+
+    let ones = <Vec<u8>>::from(tape)
+        .into_iter()
+        .filter(|symbol| *symbol == 1)
+        .count();
+    (outcome.steps(), ones)
+}
+
+#[test]
+fn busy_beaver_champions() {
+    assert_eq!(run("1RB1LB_1LA1RZ"), (6, 4));
+    assert_eq!(run("1RB1RZ_1LB0RC_1LC1LA"), (21, 5));
+    assert_eq!(run("1RB1LB_1LA0LC_1RZ1LD_1RD0RA"), (107, 13));
+}
+
+#[test]
+fn busy_beaver_round_trip() {
+    for text in [
+        "1RB1LB_1LA1RZ",
+        "1RB---_0LC1RB_1LC1LA",
+        "1RB2LA1RA_1LB1LA---",
+    ]
+    .iter()
+    {
+        let table = TransitionTable::from_bbchallenge(text).unwrap();
+        assert_eq!(table.to_bbchallenge().unwrap(), *text);
+    }
+
+    let table = TransitionTable::new('A')
+        .transition('A', 0, 'B', 1, Move::Right)
+        .transition('B', 1, 'Z', 0, Move::Left)
+        .accept('Z');
+    let text = table.to_bbchallenge().unwrap();
+    assert_eq!(text, "1RB---_---0LZ");
+
+    let parsed = TransitionTable::from_bbchallenge(&text).unwrap();
+    assert_eq!(parsed.start_state(), &'A');
+    assert_eq!(parsed.accept_states(), &['Z']);
+    assert_eq!(parsed.get(&'B', &1), Some(&('Z', 0, Move::Left)));
+}
+
+#[test]
+fn busy_beaver_invalid() {
+    for text in ["1RB1LB_1LA", "1RB1LB_1XA1RZ", "1R", "1RB1Lb_1LA1RZ"].iter() {
+        assert!(matches!(
+            TransitionTable::from_bbchallenge(text),
+            Err(Error::Format(_))
+        ));
+    }
+}
+
+#[test]
+fn busy_beaver_unrepresentable() {
+    let tables = [
+        TransitionTable::new('A').transition('A', 0, 'B', 1, Move::Stay),
+        // The accepting state B would get a group of its own
+        TransitionTable::new('A')
+            .transition('A', 0, 'C', 1, Move::Right)
+            .transition('C', 0, 'B', 1, Move::Left)
+            .accept('B'),
+        TransitionTable::new('A')
+            .transition('A', 0, 'B', 1, Move::Right)
+            .reject('B'),
+        // The text format always starts in A
+        TransitionTable::new('B')
+            .transition('B', 0, 'C', 1, Move::Right)
+            .accept('C'),
+        // B would be read back as accepting
+        TransitionTable::new('A').transition('A', 0, 'B', 1, Move::Right),
+        TransitionTable::new('A'),
+    ];
+
+    for table in tables.iter() {
+        assert!(matches!(table.to_bbchallenge(), Err(Error::Format(_))));
+    }
+}
+
+#[test]
+fn busy_beaver_undefined() {
+    let table = TransitionTable::from_bbchallenge("1RB---_0LC1RB_1LC1LA").unwrap();
+    let tape = TuringTape::new_two_way(0, 0, vec![]);
+
+    let end_state = tape.run_states(table.start(), table.end_states());
+    assert_eq!(end_state.state(), &'A');
+}
@@ -0,0 +1,74 @@
+//! In this integration test a nondeterministic turing machine is created which determines
+//! whether the input contains two consecutive `One`s, by guessing where they start.
+
+use turingmachine_rs::*;
+
+/// The Alphabet Used
+#[derive(PartialEq, Clone, Debug)]
+enum Alphabet {
+    Delta,
+    Zero,
+    One,
+}
+
+/// All the different states
+#[derive(PartialEq, Debug, Clone)]
+enum States {
+    Scanning,
+    FoundFirst,
+    ValidEnd,
+}
+
+/// The implementation for the states
+impl NondeterministicStates<Alphabet> for States {
+    fn step(&self, t: Alphabet) -> Vec<(Self, Alphabet, Move)> {
+        use Alphabet::*;
+        use States::*;
+
+        match (self, t) {
+            (Scanning, One) => vec![(Scanning, One, Move::Right), (FoundFirst, One, Move::Right)],
+            (Scanning, Zero) => vec![(Scanning, Zero, Move::Right)],
+            (FoundFirst, One) => vec![(ValidEnd, One, Move::Stay)],
+            _ => vec![],
+        }
+    }
+}
+
+#[test]
+fn contains_two_ones_accepts() {
+    use Alphabet::*;
+    use States::*;
+
+    let tape = TuringTape::new(Delta, Zero, vec![One, Zero, One, One, Zero]);
+    match tape.run_nondeterministic(Scanning, vec![ValidEnd], 100) {
+        SearchOutcome::Accepted(computation) => {
+            assert_eq!(
+                computation.states,
+                vec![Scanning, Scanning, Scanning, Scanning, FoundFirst, ValidEnd]
+            );
+            assert_eq!(computation.tape.get_cursor(), One);
+        }
+        _ => panic!("The input should be accepted"),
+    }
+
+    // The original tape is left untouched
+    assert_eq!(tape.get_cursor(), Zero);
+}
+
+#[test]
+fn contains_two_ones_rejects() {
+    use Alphabet::*;
+    use States::*;
+
+    let tape = TuringTape::new(Delta, Zero, vec![One, Zero, One]);
+    assert!(matches!(
+        tape.run_nondeterministic(Scanning, vec![ValidEnd], 100),
+        SearchOutcome::Rejected
+    ));
+
+    let tape = TuringTape::new(Delta, Zero, vec![One, Zero, One, One]);
+    assert!(matches!(
+        tape.run_nondeterministic(Scanning, vec![ValidEnd], 2),
+        SearchOutcome::DepthExhausted
+    ));
+}
@@ -0,0 +1,120 @@
+//! In this integration test a two-tape turing machine is created which copies the input on the
+//! first tape to the second tape.
+
+use turingmachine_rs::*;
+
+/// The Alphabet Used
+#[derive(PartialEq, Clone, Debug)]
+enum Alphabet {
+    Delta,
+    Zero,
+    One,
+}
+
+/// All the different states
+#[derive(PartialEq, Debug)]
+enum States {
+    Start,
+    Copying,
+    Rewinding,
+    ValidEnd,
+}
+
+/// The implementation for the states
+impl MultiTapeStates<Alphabet, 2> for States {
+    fn step(&self, [input, output]: [Alphabet; 2]) -> (Self, [Alphabet; 2], [Move; 2]) {
+        use Alphabet::*;
+        use States::*;
+
+        match self {
+            Start => (Copying, [input, output], [Move::Right, Move::Right]),
+            ValidEnd => (ValidEnd, [input, output], [Move::Stay, Move::Stay]),
+
+            Copying => match input {
+                Delta => (Rewinding, [input, output], [Move::Left, Move::Left]),
+                _ => (Copying, [input.clone(), input], [Move::Right, Move::Right]),
+            },
+
+            Rewinding => match output {
+                Delta => (ValidEnd, [input, output], [Move::Stay, Move::Stay]),
+                _ => (Rewinding, [input, output], [Move::Left, Move::Left]),
+            },
+        }
+    }
+}
+
+#[test]
+fn copy_proper_output() {
+    use Alphabet::*;
+    use States::*;
+
+    let machine = MultiTapeMachine::new([
+        TuringTape::new(Delta, Delta, vec![One, Zero, One]),
+        TuringTape::new(Delta, Delta, vec![]),
+    ]);
+    assert_eq!(machine.run_states(Start, vec![ValidEnd]), ValidEnd);
+
+    let [input, output] = machine.into_tapes();
+    assert_eq!(
+        <Vec<Alphabet>>::from(input),
+        vec![Delta, One, Zero, One, Delta]
+    );
+    assert_eq!(
+        <Vec<Alphabet>>::from(output),
+        vec![Delta, One, Zero, One, Delta]
+    );
+}
+
+#[test]
+fn copy_limited() {
+    use Alphabet::*;
+    use States::*;
+
+    let machine = MultiTapeMachine::new([
+        TuringTape::new(Delta, Delta, vec![One, Zero, One]),
+        TuringTape::new(Delta, Delta, vec![]),
+    ]);
+    assert_eq!(
+        machine.run_states_limited(Start, vec![ValidEnd], 3),
+        RunOutcome::OutOfSteps {
+            state: Copying,
+            steps: 3
+        }
+    );
+    assert_eq!(machine.get_cursors(), [One, Delta]);
+}
+
+#[test]
+fn copy_observed() {
+    use Alphabet::*;
+    use States::*;
+
+    /// Remembers whether the machine halted and after how many steps
+    #[derive(Default)]
+    struct Halted(Option<usize>);
+
+    impl MultiTapeObserver<Alphabet, States, 2> for Halted {
+        fn on_halt(&mut self, _machine: &MultiTapeMachine<Alphabet, 2>, _: &States, steps: usize) {
+            self.0 = Some(steps);
+        }
+    }
+
+    let machine = MultiTapeMachine::new([
+        TuringTape::new(Delta, Delta, vec![One, Zero, One]),
+        TuringTape::new(Delta, Delta, vec![]),
+    ]);
+    let mut counter = StepCounter::default();
+    assert_eq!(
+        machine.run_states_observed(Start, vec![ValidEnd], &mut counter),
+        ValidEnd
+    );
+    assert_eq!(counter.steps, 9);
+
+    let machine = MultiTapeMachine::new([
+        TuringTape::new(Delta, Delta, vec![One]),
+        TuringTape::new(Delta, Delta, vec![]),
+    ]);
+    let mut halted = Halted::default();
+    machine.run_states_observed(Start, vec![ValidEnd], &mut halted);
+    assert_eq!(halted.0, Some(5));
+}
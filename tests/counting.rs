@@ -0,0 +1,74 @@
+//! In this integration test a turing machine is created which writes ones while counting them in
+//! its state. It halts once the count exceeds a bound, which is given as a predicate instead of
+//! a list of end states.
+
+use turingmachine_rs::*;
+
+/// The Alphabet Used
+#[derive(PartialEq, Clone, Debug)]
+enum Alphabet {
+    Delta,
+    One,
+}
+
+/// All the different states
+#[derive(PartialEq, Clone, Debug)]
+enum States {
+    Counting(usize),
+}
+
+/// The implementation for the states
+impl TuringStates<Alphabet> for States {
+    fn step(&self, _t: Alphabet) -> (Self, Alphabet, Move) {
+        let States::Counting(n) = self;
+        (States::Counting(n + 1), Alphabet::One, Move::Right)
+    }
+}
+
+#[test]
+fn counting_halts_on_predicate() {
+    use Alphabet::*;
+    use States::*;
+
+    let exceeds = |bound| move |state: &States| matches!(state, Counting(n) if *n > bound);
+
+    let tape = TuringTape::new(Delta, Delta, vec![]);
+    assert_eq!(tape.run_states(Counting(0), exceeds(3)), Counting(4));
+    assert_eq!(<Vec<Alphabet>>::from(tape), vec![One, One, One, One, Delta]);
+
+    assert_eq!(
+        States::run_until_end_limited(Counting(0), exceeds(100), Delta, Delta, vec![], 10).0,
+        RunOutcome::OutOfSteps {
+            state: Counting(10),
+            steps: 10
+        }
+    );
+
+    let tape = TuringTape::new(Delta, Delta, vec![]);
+    assert_eq!(tape.execute(Counting(2), exceeds(4)).count(), 3);
+}
+
+#[test]
+fn counting_halts_on_listed_states() {
+    use Alphabet::*;
+    use States::*;
+
+    let tape = TuringTape::new(Delta, Delta, vec![]);
+    assert_eq!(
+        tape.try_run_states(Counting(0), [Counting(2)]),
+        Ok(Counting(2))
+    );
+    assert_eq!(
+        tape.run_states_limited(Counting(2), vec![Counting(1)], 5),
+        RunOutcome::OutOfSteps {
+            state: Counting(7),
+            steps: 5
+        }
+    );
+
+    // References to the end states can be reused between runs
+    let end_states = vec![Counting(3)];
+    let tape = TuringTape::new(Delta, Delta, vec![]);
+    assert_eq!(tape.run_states(Counting(0), &end_states), Counting(3));
+    assert_eq!(tape.run_states(Counting(1), &end_states), Counting(3));
+}
@@ -0,0 +1,122 @@
+//! In this integration test the state transition diagrams of turing machines are exported as
+//! Graphviz DOT graphs.
+
+use turingmachine_rs::*;
+
+/// The Alphabet Used
+#[derive(PartialEq, Clone, Debug)]
+enum Alphabet {
+    Delta,
+    Zero,
+    One,
+}
+
+impl std::fmt::Display for Alphabet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Alphabet::Delta => write!(f, "_"),
+            Alphabet::Zero => write!(f, "0"),
+            Alphabet::One => write!(f, "1"),
+        }
+    }
+}
+
+/// All the different states
+#[derive(PartialEq, Debug)]
+enum States {
+    Started,
+    ValidEnd,
+}
+
+/// Replaces all the Ones in the input with zeros
+impl PartialTuringStates<Alphabet> for States {
+    fn partial_step(&self, t: Alphabet) -> Option<(Self, Alphabet, Move)> {
+        use Alphabet::*;
+        use States::*;
+
+        match (self, t) {
+            (Started, Zero) => Some((Started, Zero, Move::Right)),
+            (Started, One) => Some((Started, Zero, Move::Right)),
+            (Started, Delta) => Some((ValidEnd, Delta, Move::Stay)),
+            (ValidEnd, _) => None,
+        }
+    }
+}
+
+#[test]
+fn dot_from_states() {
+    use Alphabet::*;
+    use States::*;
+
+    assert_eq!(
+        states_to_dot(
+            &[Started, ValidEnd],
+            &[Delta, Zero, One],
+            &Started,
+            &[ValidEnd]
+        ),
+        r#"digraph {
+    rankdir=LR;
+    node [shape=circle];
+    "Started";
+    "ValidEnd" [shape=doublecircle];
+    __start [shape=point];
+    __start -> "Started";
+    "Started" -> "ValidEnd" [label="_ → _, S"];
+    "Started" -> "Started" [label="0 → 0, R\n1 → 0, R"];
+}
+"#
+    );
+}
+
+#[test]
+fn dot_from_table() {
+    let table = TransitionTable::from_bbchallenge("1RB1LB_1LA1RZ").unwrap();
+
+    assert_eq!(
+        table.to_dot(),
+        r#"digraph {
+    rankdir=LR;
+    node [shape=circle];
+    "A";
+    "B";
+    "Z" [shape=doublecircle];
+    __start [shape=point];
+    __start -> "A";
+    "A" -> "B" [label="0 → 1, R\n1 → 1, L"];
+    "B" -> "A" [label="0 → 1, L"];
+    "B" -> "Z" [label="1 → 1, R"];
+}
+"#
+    );
+}
+
+#[test]
+fn dot_from_table_with_reject() {
+    let table = TransitionTable::new("even")
+        .transition("even", '1', "odd", '1', Move::Right)
+        .transition("even", '_', "accept", '_', Move::Stay)
+        .transition("odd", '1', "even", '1', Move::Right)
+        .transition("odd", '_', "reject", '_', Move::Stay)
+        .accept("accept")
+        .reject("reject");
+
+    assert_eq!(
+        table.to_dot(),
+        r#"digraph {
+    rankdir=LR;
+    node [shape=circle];
+    "accept" [shape=doublecircle];
+    "even";
+    "odd";
+    "reject" [shape=octagon];
+    __start [shape=point];
+    __start -> "even";
+    "even" -> "accept" [label="_ → _, S"];
+    "even" -> "odd" [label="1 → 1, R"];
+    "odd" -> "even" [label="1 → 1, R"];
+    "odd" -> "reject" [label="_ → _, S"];
+}
+"#
+    );
+}
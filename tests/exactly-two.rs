@@ -56,6 +56,17 @@ impl TuringStates<Alphabet> for States {
     }
 }
 
+/// The accepting and rejecting end states
+impl Decider<Alphabet> for States {
+    fn is_accepting(&self) -> bool {
+        *self == States::ValidEnd
+    }
+
+    fn is_rejecting(&self) -> bool {
+        matches!(self, States::FoundMore | States::InvalidEnd)
+    }
+}
+
 #[test]
 fn exactly_two_proper_output() {
     use turingmachine_rs::TuringStates;
@@ -72,3 +83,33 @@ fn exactly_two_proper_output() {
         (ValidEnd, vec![Delta, Zero, Zero, Zero, One, One, Delta])
     );
 }
+
+#[test]
+fn exactly_two_decides_words() {
+    use Alphabet::*;
+
+    let recognizer = Recognizer::new(States::Start, Delta, Delta, 100);
+
+    for word in [
+        vec![One, One],
+        vec![Zero, One, Zero, One],
+        vec![One, Zero, One, Zero],
+    ] {
+        assert_eq!(recognizer.decide(word), Verdict::Accept);
+    }
+    for word in [
+        vec![],
+        vec![One],
+        vec![Zero, Zero],
+        vec![One, One, Zero, One],
+    ] {
+        assert_eq!(recognizer.decide(word), Verdict::Reject);
+    }
+
+    let impatient = Recognizer::new(States::Start, Delta, Delta, 4);
+    assert!(impatient.accepts(vec![One, One]));
+    assert_eq!(
+        impatient.decide(vec![Zero, One, One]),
+        Verdict::Undecided(4)
+    );
+}
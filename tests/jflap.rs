@@ -0,0 +1,145 @@
+//! In this integration test JFLAP `.jff` files are loaded and run.
+#![cfg(feature = "jflap")]
+
+use turingmachine_rs::*;
+
+/// Replaces every `a` by `b` and accepts once it reaches the blank after the input
+const REPLACE_A: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="no"?><!--Created with JFLAP 7.1.--><structure>
+	<type>turing</type>
+	<automaton>
+		<!--The list of states.-->
+		<state id="0" name="q0">
+			<x>80.0</x>
+			<y>120.0</y>
+			<initial/>
+		</state>
+		<state id="1" name="q1">
+			<x>240.0</x>
+			<y>120.0</y>
+			<final/>
+		</state>
+		<!--The list of transitions.-->
+		<transition>
+			<from>0</from>
+			<to>0</to>
+			<read>a</read>
+			<write>b</write>
+			<move>R</move>
+		</transition>
+		<transition>
+			<from>0</from>
+			<to>0</to>
+			<read>b</read>
+			<write>b</write>
+			<move>R</move>
+		</transition>
+		<transition>
+			<from>0</from>
+			<to>1</to>
+			<read/>
+			<write/>
+			<move>S</move>
+		</transition>
+	</automaton>
+</structure>"#;
+
+/// Copies the input on the first tape to the second tape
+const COPY: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="no"?><structure>
+	<type>turing</type>
+	<tapes>2</tapes>
+	<automaton>
+		<state id="0" name="q0"><initial/></state>
+		<state id="1" name="q1"><final/></state>
+		<transition>
+			<from>0</from>
+			<to>0</to>
+			<read tape="1">1</read>
+			<write tape="1">1</write>
+			<move tape="1">R</move>
+			<read tape="2"/>
+			<write tape="2">1</write>
+			<move tape="2">R</move>
+		</transition>
+		<transition>
+			<from>0</from>
+			<to>1</to>
+			<read tape="1"/>
+			<write tape="1"/>
+			<move tape="1">S</move>
+			<read tape="2"/>
+			<write tape="2"/>
+			<move tape="2">L</move>
+		</transition>
+	</automaton>
+</structure>"#;
+
+#[test]
+fn jflap_single_tape() {
+    let table = TransitionTable::from_jff(REPLACE_A).unwrap();
+
+    let tape = TuringTape::from_jflap_input("abba");
+    let end_state = tape.run_states(table.start(), table.end_states());
+    assert_eq!(end_state.state(), "q1");
+    assert!(end_state.is_accepting());
+    assert_eq!(
+        <Vec<char>>::from(tape),
+        vec!['b', 'b', 'b', 'b', JFLAP_BLANK]
+    );
+
+    let tape = TuringTape::from_jflap_input("abc");
+    assert_eq!(
+        tape.run_states_limited(table.start(), table.end_states(), 100),
+        RunOutcome::UndefinedTransition {
+            state: table.state("q0".to_string()),
+            steps: 2
+        }
+    );
+}
+
+#[test]
+fn jflap_multi_tape() {
+    let table = MultiTapeTable::<_, _, 2>::from_jff(COPY).unwrap();
+
+    let machine = MultiTapeMachine::new([
+        TuringTape::from_jflap_input("111"),
+        TuringTape::from_jflap_input(""),
+    ]);
+    let end_state = machine.run_states(table.start(), table.end_states());
+    assert!(end_state.is_accepting());
+
+    let [input, output] = machine.into_tapes();
+    assert_eq!(<Vec<char>>::from(input), vec!['1', '1', '1', JFLAP_BLANK]);
+    assert_eq!(<Vec<char>>::from(output), vec!['1', '1', '1', JFLAP_BLANK]);
+
+    // No transition is defined for a 0, which halts the machine without accepting
+    let machine = MultiTapeMachine::new([
+        TuringTape::from_jflap_input("101"),
+        TuringTape::from_jflap_input(""),
+    ]);
+    let end_state = machine.run_states(table.start(), table.end_states());
+    assert!(!end_state.is_accepting());
+    assert_eq!(machine.get_cursors(), ['0', JFLAP_BLANK]);
+
+    assert!(matches!(
+        TransitionTable::from_jff(COPY),
+        Err(Error::Format(_))
+    ));
+}
+
+#[test]
+fn jflap_unsupported() {
+    let wildcard = REPLACE_A.replace("<read>b</read>", "<read>~</read>");
+    let nondeterministic = REPLACE_A.replace("<read>b</read>", "<read>a</read>");
+    let block = REPLACE_A.replace(
+        "<!--The list of transitions.-->",
+        r#"<block id="2" name="q2"><tag>Machine2</tag></block>"#,
+    );
+    let finite_automaton = REPLACE_A.replace("<type>turing</type>", "<type>fa</type>");
+
+    for xml in [wildcard, nondeterministic, block, finite_automaton].iter() {
+        assert!(matches!(
+            TransitionTable::from_jff(xml),
+            Err(Error::Format(_))
+        ));
+    }
+}
@@ -0,0 +1,71 @@
+//! In this integration test an alphabet derives `TapeSymbol` and tapes are round-tripped through
+//! strings.
+#![cfg(feature = "derive")]
+
+use std::convert::TryFrom;
+use turingmachine_rs::*;
+
+/// The Alphabet Used
+#[derive(TapeSymbol, Clone, Debug, PartialEq)]
+enum Alphabet {
+    #[symbol('_')]
+    Delta,
+    #[symbol('0')]
+    Zero,
+    #[symbol('1')]
+    One,
+}
+
+#[test]
+fn tape_symbol_conversions() {
+    use Alphabet::*;
+
+    assert_eq!(Alphabet::symbols(), vec![Delta, Zero, One]);
+    assert_eq!(
+        Alphabet::symbols()
+            .iter()
+            .map(TapeSymbol::to_char)
+            .collect::<String>(),
+        "_01"
+    );
+    assert_eq!(Alphabet::from_char('1'), Some(One));
+    assert_eq!(Alphabet::from_char('2'), None);
+    assert_eq!(Alphabet::try_from('0'), Ok(Zero));
+    assert!(Alphabet::try_from('x').is_err());
+    assert_eq!("_".parse::<Alphabet>(), Ok(Delta));
+    assert!("01".parse::<Alphabet>().is_err());
+    assert!("".parse::<Alphabet>().is_err());
+}
+
+#[test]
+fn tape_symbol_round_trip() {
+    use Alphabet::*;
+
+    let tape = TuringTape::new(Delta, Delta, Alphabet::parse_symbols("0110").unwrap());
+    tape.step_right();
+    tape.step_right();
+    tape.step_right();
+    tape.step_right();
+    tape.step_right();
+
+    assert_eq!(tape.to_string(), "|  _  |  0  |  1  |  1  |  0  |> _ <|");
+    assert_eq!(
+        <Vec<Alphabet>>::from(tape)
+            .iter()
+            .map(ToString::to_string)
+            .collect::<String>(),
+        "_0110_"
+    );
+    assert!(Alphabet::parse_symbols("01x").is_err());
+}
+
+#[test]
+fn tape_symbol_compact_strings() {
+    use Alphabet::*;
+
+    let tape = TuringTape::from_str_with("_0[1]1", Delta, Alphabet::from_char).unwrap();
+    assert_eq!(tape.get_cursor(), One);
+    tape.step_right();
+    tape.step_right();
+    assert_eq!(tape.to_compact_string(), "_011[_]");
+}
@@ -0,0 +1,69 @@
+//! In this integration test a turing machine is loaded into a transition table, which determines
+//! whether the input contains an even amount of `1`s.
+
+use turingmachine_rs::*;
+
+/// Build the table from a textual description, like one loaded at runtime
+fn even_ones() -> TransitionTable<String, char> {
+    let description = "
+        even 0 even 0 R
+        even 1 odd 1 R
+        even _ accept _ S
+        odd 0 odd 0 R
+        odd 1 even 1 R
+        odd _ reject _ S
+    ";
+
+    description
+        .lines()
+        .map(|line| line.split_whitespace().collect::<Vec<_>>())
+        .filter(|fields| !fields.is_empty())
+        .fold(TransitionTable::new("even".to_string()), |table, fields| {
+            let token = |field: &str| field.chars().next().unwrap();
+            let mv = match fields[4] {
+                "L" => Move::Left,
+                "S" => Move::Stay,
+                _ => Move::Right,
+            };
+
+            table.transition(
+                fields[0].to_string(),
+                token(fields[1]),
+                fields[2].to_string(),
+                token(fields[3]),
+                mv,
+            )
+        })
+        .accept("accept".to_string())
+        .reject("reject".to_string())
+}
+
+#[test]
+fn transition_table_proper_output() {
+    let table = even_ones();
+
+    let tape = TuringTape::new('_', '1', vec!['0', '0', '1']);
+    let end_state = tape.run_states(table.start(), table.end_states());
+    assert_eq!(end_state.state(), "accept");
+    assert!(end_state.is_accepting());
+
+    let tape = TuringTape::new('_', '1', vec!['1', '1']);
+    let end_state = tape.run_states(table.start(), table.end_states());
+    assert_eq!(end_state.state(), "reject");
+    assert!(end_state.is_rejecting());
+}
+
+#[test]
+fn transition_table_undefined_transition() {
+    let table = even_ones();
+
+    let tape = TuringTape::new('_', '0', vec!['2']);
+    let outcome = tape.run_states_limited(table.start(), table.end_states(), 100);
+    assert_eq!(
+        outcome,
+        RunOutcome::UndefinedTransition {
+            state: table.state("even".to_string()),
+            steps: 1
+        }
+    );
+}
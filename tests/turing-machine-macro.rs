@@ -0,0 +1,81 @@
+//! In this integration test the `turing_machine!` macro is used to define a turing machine which
+//! determines whether the input contains exactly two `One`s.
+
+use turingmachine_rs::*;
+
+/// The Alphabet Used
+#[derive(PartialEq, Clone, Debug)]
+enum Alphabet {
+    Delta,
+    Zero,
+    One,
+}
+
+turing_machine! {
+    enum States: Alphabet {
+        Start,
+        FoundNone,
+        FoundFirst,
+        FoundSecond,
+        FoundMore,
+        InvalidEnd,
+        ValidEnd,
+    }
+
+    end_states = [FoundMore, InvalidEnd, ValidEnd];
+
+    Start, _ => FoundNone, _, R;
+
+    FoundNone, One => FoundFirst, _, R;
+    FoundFirst, One => FoundSecond, _, R;
+    FoundSecond, One => FoundMore, _, R;
+
+    FoundSecond, Delta => ValidEnd, _, S;
+    FoundNone, Delta => InvalidEnd, _, S;
+    FoundFirst, Delta => InvalidEnd, _, S;
+
+    FoundNone, Zero => FoundNone, _, R;
+    FoundFirst, Zero => FoundFirst, _, R;
+    FoundSecond, Zero => FoundSecond, _, R;
+}
+
+#[test]
+fn turing_machine_macro_proper_output() {
+    use Alphabet::*;
+    use States::*;
+
+    assert_eq!(States::end_states(), vec![FoundMore, InvalidEnd, ValidEnd]);
+    assert_eq!(
+        States::run_until_end(
+            Start,
+            States::end_states(),
+            Delta,
+            Delta,
+            vec![Zero, One, Zero, One]
+        ),
+        (ValidEnd, vec![Delta, Zero, One, Zero, One, Delta])
+    );
+    assert_eq!(
+        States::run_until_end(
+            Start,
+            States::end_states(),
+            Delta,
+            Delta,
+            vec![One, One, One]
+        )
+        .0,
+        FoundMore
+    );
+}
+
+#[test]
+fn turing_machine_macro_undefined_transition() {
+    use Alphabet::*;
+    use States::*;
+
+    assert_eq!(ValidEnd.partial_step(Delta), None);
+    assert_eq!(
+        FoundFirst.partial_step(One),
+        Some((FoundSecond, One, Move::Right))
+    );
+}
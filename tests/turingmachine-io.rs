@@ -0,0 +1,95 @@
+//! In this integration test a turingmachine.io machine description is loaded, run and exported.
+#![cfg(feature = "yaml")]
+
+use turingmachine_rs::*;
+
+const BINARY_INCREMENT: &str = "
+# Adds 1 to a binary number.
+input: '1011'
+blank: ' '
+start state: right
+table:
+  # scan to the rightmost digit
+  right:
+    [1,0]: R
+    ' '  : {L: carry}
+  # then carry the 1
+  carry:
+    1      : {write: 0, L}
+    [0,' ']: {write: 1, L: done}
+  done:
+";
+
+#[test]
+fn turingmachine_io_import() {
+    let (table, tape) = TransitionTable::from_turingmachine_io(BINARY_INCREMENT).unwrap();
+
+    let end_state = tape.run_states(table.start(), table.end_states());
+    assert_eq!(end_state.state(), "done");
+    assert_eq!(<Vec<char>>::from(tape), vec!['1', '1', '0', '0', ' ']);
+}
+
+#[test]
+fn turingmachine_io_round_trip() {
+    let (table, tape) = TransitionTable::from_turingmachine_io(BINARY_INCREMENT).unwrap();
+    let yaml = table.to_turingmachine_io(&tape).unwrap();
+
+    let (reloaded, reloaded_tape) = TransitionTable::from_turingmachine_io(&yaml).unwrap();
+    assert_eq!(reloaded.start_state(), "right");
+    assert_eq!(
+        reloaded.get(&"carry".to_string(), &'1'),
+        table.get(&"carry".to_string(), &'1')
+    );
+    assert_eq!(reloaded.transitions().count(), 6);
+    assert_eq!(reloaded.to_turingmachine_io(&reloaded_tape).unwrap(), yaml);
+    assert_eq!(<Vec<char>>::from(reloaded_tape), vec!['1', '0', '1', '1']);
+}
+
+#[test]
+fn turingmachine_io_unsupported() {
+    let invalid = [
+        "blank: ' '\nstart state: a\ntable:\n  a:\n    '10': R\n",
+        "blank: ' '\nstart state: a\ntable:\n  a:\n    1: {S: a}\n",
+        "blank: ' '\nstart state: a\ntable:\n  a:\n    1: {R: b}\n",
+        "blank: ' '\nstart state: a\n",
+        "blank: ' '\nstart state: a\ntable:\n  a:\nnodes: []\n",
+    ];
+
+    for yaml in invalid.iter() {
+        assert!(matches!(
+            TransitionTable::from_turingmachine_io(yaml),
+            Err(Error::Format(_))
+        ));
+    }
+
+    let table = TransitionTable::new("a".to_string()).transition(
+        "a".to_string(),
+        '1',
+        "a".to_string(),
+        '1',
+        Move::Stay,
+    );
+    let tape = TuringTape::new(' ', '1', vec![]);
+    assert!(table.to_turingmachine_io(&tape).is_err());
+
+    let state = |name: &str| name.to_string();
+    let tables = [
+        // Rule-less states are read back as accepting
+        TransitionTable::new(state("a"))
+            .transition(state("a"), '1', state("no"), '1', Move::Right)
+            .accept(state("yes"))
+            .reject(state("no")),
+        TransitionTable::new(state("a")).transition(state("a"), '1', state("b"), '1', Move::Right),
+        // Accepting states with rules would not halt when read back
+        TransitionTable::new(state("a"))
+            .transition(state("a"), '1', state("a"), '1', Move::Right)
+            .accept(state("a")),
+    ];
+
+    for table in tables.iter() {
+        assert!(matches!(
+            table.to_turingmachine_io(&tape),
+            Err(Error::Format(_))
+        ));
+    }
+}
@@ -0,0 +1,125 @@
+//! Derive macros for the turingmachine-rs crate
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, LitChar};
+
+/// Derive `TapeSymbol`, `Display`, `FromStr` and `TryFrom<char>` for an alphabet enum
+///
+/// Every variant has to be a unit variant with a `#[symbol('c')]` attribute giving the
+/// character it is written as. The characters have to be distinct.
+#[proc_macro_derive(TapeSymbol, attributes(symbol))]
+pub fn derive_tape_symbol(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let data = match &input.data {
+        Data::Enum(data) => data,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "TapeSymbol can only be derived for enums",
+            ))
+        }
+    };
+
+    let mut variants = Vec::new();
+    let mut chars: Vec<LitChar> = Vec::new();
+
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "TapeSymbol variants cannot have fields",
+            ));
+        }
+
+        let mut symbol = None;
+        for attr in variant.attrs.iter().filter(|a| a.path().is_ident("symbol")) {
+            if symbol.is_some() {
+                return Err(syn::Error::new_spanned(attr, "duplicate symbol attribute"));
+            }
+            symbol = Some(attr.parse_args::<LitChar>()?);
+        }
+
+        let symbol = symbol
+            .ok_or_else(|| syn::Error::new_spanned(variant, "missing #[symbol('c')] attribute"))?;
+
+        if let Some(other) = chars.iter().find(|c| c.value() == symbol.value()) {
+            let mut error = syn::Error::new_spanned(&symbol, "duplicate symbol character");
+            error.combine(syn::Error::new_spanned(other, "first used here"));
+            return Err(error);
+        }
+
+        variants.push(&variant.ident);
+        chars.push(symbol);
+    }
+
+    Ok(quote! {
+        impl #impl_generics ::turingmachine_rs::TapeSymbol for #name #ty_generics #where_clause {
+            fn to_char(&self) -> char {
+                match self {
+                    #(#name::#variants => #chars,)*
+                }
+            }
+
+            fn from_char(c: char) -> ::core::option::Option<Self> {
+                match c {
+                    #(#chars => ::core::option::Option::Some(#name::#variants),)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            fn symbols() -> ::std::vec::Vec<Self> {
+                ::std::vec![#(#name::#variants),*]
+            }
+        }
+
+        impl #impl_generics ::core::fmt::Display for #name #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Write::write_char(
+                    f,
+                    ::turingmachine_rs::TapeSymbol::to_char(self),
+                )
+            }
+        }
+
+        impl #impl_generics ::core::convert::TryFrom<char> for #name #ty_generics #where_clause {
+            type Error = ::turingmachine_rs::Error;
+
+            fn try_from(c: char) -> ::core::result::Result<Self, Self::Error> {
+                <Self as ::turingmachine_rs::TapeSymbol>::from_char(c).ok_or_else(|| {
+                    ::turingmachine_rs::Error::Format(::std::format!(
+                        "unknown tape symbol '{}'",
+                        c
+                    ))
+                })
+            }
+        }
+
+        impl #impl_generics ::core::str::FromStr for #name #ty_generics #where_clause {
+            type Err = ::turingmachine_rs::Error;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                let mut chars = s.chars();
+                match (chars.next(), chars.next()) {
+                    (::core::option::Option::Some(c), ::core::option::Option::None) => {
+                        <Self as ::core::convert::TryFrom<char>>::try_from(c)
+                    }
+                    _ => ::core::result::Result::Err(::turingmachine_rs::Error::Format(
+                        ::std::format!("expected a single tape symbol, found '{}'", s),
+                    )),
+                }
+            }
+        }
+    })
+}